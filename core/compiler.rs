@@ -9,54 +9,59 @@ pub use statements::*;
 
 use sflyn_parser::File;
 
-use super::Environment;
+use super::{
+  Environment,
+  error::show_diagnostic,
+};
 
-pub fn run(
-  file: File,
-  environment: &mut Environment,
-  with_stdlib: bool,
-) {
-  let mut showed: bool = false;
+/// Evaluate the standard library files.
+pub fn run_stdlibs(environment: &mut Environment) -> i32 {
+  for (name, file) in environment.stdlibs.clone().iter() {
+    if name == "builtins" || file.statements.is_empty() {
+      continue;
+    }
 
-  // Evaluate stdlib.
-  if environment.stdlibs.len() > 0 && with_stdlib {
-    for (name, file) in environment.stdlibs.clone().iter() {
-      if name == "builtins" || file.statements.len() == 0 {
-        continue;
-      }
+    environment.running_file = file.name.clone();
 
-      for statement in file.statements.iter() {
-        // Evaluate the statement.
-        if let Some(object) = evaluate_statement(statement, environment) {
-          // Check if the object is an error.
-          if let Some(error) = object.get_error() {
-            println!(
-              "{}{}",
-              if showed { "\n" } else { "" },
-              error.string(file.clone())
-            );
-
-            showed = true;
-          }
+    for statement in file.statements.iter() {
+      // Evaluate the statement.
+      if let Some(object) = evaluate_statement(statement, environment) {
+        // Check if the object is an error.
+        if let Some(error) = object.get_error() {
+          show_diagnostic(file, &error.get_diagnostic(), &environment.arguments.error_format);
+          return 1;
         }
       }
     }
   }
 
+  0
+}
+
+pub fn run(
+  file: File,
+  environment: &mut Environment,
+  with_stdlib: bool,
+) -> i32 {
+  // Evaluate stdlib.
+  if with_stdlib && run_stdlibs(environment) != 0 {
+    return 1;
+  }
+
+  environment.running_file = file.name.clone();
+
   // Evaluate file statements.
   for statement in file.statements.iter() {
     // Evaluate the statement.
     if let Some(object) = evaluate_statement(statement, environment) {
       // Check if the object is an error.
       if let Some(error) = object.get_error() {
-        println!(
-          "{}{}",
-          if showed { "\n" } else { "" },
-          error.string(file.clone())
-        );
-
-        showed = true;
+        // An uncaught error stops the program.
+        show_diagnostic(&file, &error.get_diagnostic(), &environment.arguments.error_format);
+        return 1;
       }
     }
   }
+
+  0
 }
@@ -1,4 +1,10 @@
+mod array;
+mod boolean;
+mod enum_variant;
+mod null;
+mod number;
 mod print;
+mod string;
 
 use sflyn_parser::tokens::Token;
 
@@ -6,8 +12,26 @@ use super::{
   BuiltIn,
   Error,
   Objects,
+  StringO,
 };
 
+type PropertyFn = fn(
+  object: &Objects,
+) -> Box<Objects>;
+
+type MethodFn = fn(
+  token: Token,
+  object: &Objects,
+  arguments: Vec<Box<Objects>>,
+) -> Box<Objects>;
+
+/// A native member of a value, the types of the members are declared in the `std` files.
+#[derive(Clone)]
+pub enum Members {
+  PROPERTY(PropertyFn),
+  METHOD(MethodFn),
+}
+
 pub fn get_builtin_for_identifier(identifier: Token) -> Box<Objects> {
   // Print
   if identifier.value == "print" {
@@ -20,3 +44,37 @@ pub fn get_builtin_for_identifier(identifier: Token) -> Box<Objects> {
     identifier.clone(),
   )
 }
+
+/// Get the native member of a value from the members of its type.
+pub fn get_builtin_member(object: &Objects, name: &str) -> Option<Members> {
+  match object {
+    Objects::ARRAY(_) => array::get_member(name),
+    Objects::BOOLEAN(_) => boolean::get_member(name),
+    Objects::ENUMVARIANT(_) => enum_variant::get_member(name),
+    Objects::NULL(_) => null::get_member(name),
+    Objects::NUMBER(_) => number::get_member(name),
+    Objects::STRING(_) => string::get_member(name),
+    _ => None,
+  }
+}
+
+/// Check the number of arguments of a native method.
+fn check_arguments(token: &Token, arguments: &[Box<Objects>], length: usize) -> Option<Box<Objects>> {
+  if arguments.len() != length {
+    return Some(Error::new(
+      format!("expect `{}` argument, got `{}` instead.", length, arguments.len()),
+      token.clone(),
+    ));
+  }
+
+  None
+}
+
+/// The `toString()` method of every type.
+fn to_string(token: Token, object: &Objects, arguments: Vec<Box<Objects>>) -> Box<Objects> {
+  if let Some(error) = check_arguments(&token, &arguments, 0) {
+    return error;
+  }
+
+  StringO::new(object.string())
+}
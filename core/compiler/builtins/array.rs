@@ -0,0 +1,35 @@
+use crate::compiler::{
+  Number,
+  Objects,
+};
+
+use sflyn_parser::tokens::Token;
+
+use super::{
+  check_arguments,
+  Members,
+  to_string,
+};
+
+pub fn get_member(name: &str) -> Option<Members> {
+  match name {
+    "length" => Some(Members::PROPERTY(length)),
+    "push" => Some(Members::METHOD(push)),
+    "toString" => Some(Members::METHOD(to_string)),
+    _ => None,
+  }
+}
+
+fn length(object: &Objects) -> Box<Objects> {
+  Number::new(object.get_array().unwrap().get_length() as f64)
+}
+
+fn push(token: Token, object: &Objects, arguments: Vec<Box<Objects>>) -> Box<Objects> {
+  if let Some(error) = check_arguments(&token, &arguments, 1) {
+    return error;
+  }
+
+  let length = object.get_array().unwrap().push_element(arguments[0].clone());
+
+  Number::new(length as f64)
+}
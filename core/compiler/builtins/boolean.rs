@@ -0,0 +1,11 @@
+use super::{
+  Members,
+  to_string,
+};
+
+pub fn get_member(name: &str) -> Option<Members> {
+  match name {
+    "toString" => Some(Members::METHOD(to_string)),
+    _ => None,
+  }
+}
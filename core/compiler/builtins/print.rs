@@ -21,14 +21,13 @@ pub fn print(token: Token, arguments: Vec<Box<Objects>>) -> Box<Objects> {
   let stdout = io::stdout();
   let mut handle = stdout.lock();
 
-  let string =
-    arguments[0].clone().string()
-      .replace("\\r", "\r")
-      .replace("\\n", "\n")
-      .replace("\\t", "\t");
-
-  handle.write(string.as_bytes()).unwrap();
-  handle.write(b"\n").unwrap();
+  // Throw a catchable error when the message can not be written.
+  if let Err(error) = handle.write_all(format!("{}\n", arguments[0].string()).as_bytes()) {
+    return Error::new(
+      format!("can not print the message: {}.", error),
+      token,
+    );
+  }
 
   arguments[0].clone()
 }
@@ -0,0 +1,91 @@
+use crate::compiler::{
+  Array,
+  Error,
+  Number,
+  Objects,
+  StringO,
+};
+
+use sflyn_parser::tokens::Token;
+
+use super::{
+  check_arguments,
+  Members,
+  to_string,
+};
+
+pub fn get_member(name: &str) -> Option<Members> {
+  match name {
+    "length" => Some(Members::PROPERTY(length)),
+    "slice" => Some(Members::METHOD(slice)),
+    "split" => Some(Members::METHOD(split)),
+    "toString" => Some(Members::METHOD(to_string)),
+    _ => None,
+  }
+}
+
+fn length(object: &Objects) -> Box<Objects> {
+  Number::new(object.get_string().unwrap().get_value().chars().count() as f64)
+}
+
+/// Get the position of a `slice` index, the negative ones count from the end.
+fn get_slice_position(index: &Objects, length: usize) -> Option<usize> {
+  let index = match index.get_number() {
+    Some(number) => number.get_value(),
+    None => {
+      return None;
+    },
+  };
+
+  let position = if index < 0.0 { length as f64 + index } else { index };
+
+  Some(position.max(0.0).min(length as f64) as usize)
+}
+
+fn slice(token: Token, object: &Objects, arguments: Vec<Box<Objects>>) -> Box<Objects> {
+  if let Some(error) = check_arguments(&token, &arguments, 2) {
+    return error;
+  }
+
+  // The positions count the characters, not the bytes.
+  let characters: Vec<char> = object.get_string().unwrap().get_value().chars().collect();
+
+  let start = get_slice_position(&arguments[0], characters.len());
+  let end = get_slice_position(&arguments[1], characters.len());
+
+  match (start, end) {
+    (Some(start), Some(end)) if start < end => StringO::new(characters[start..end].iter().collect()),
+    (Some(_), Some(_)) => StringO::new(String::new()),
+    _ => Error::new(
+      String::from("the `slice` positions must be numbers."),
+      token,
+    ),
+  }
+}
+
+fn split(token: Token, object: &Objects, arguments: Vec<Box<Objects>>) -> Box<Objects> {
+  if let Some(error) = check_arguments(&token, &arguments, 1) {
+    return error;
+  }
+
+  let separator = match arguments[0].get_string() {
+    Some(separator) => separator.get_value(),
+    None => {
+      return Error::new(
+        format!("`{}` is not a valid separator.", arguments[0].string()),
+        token,
+      );
+    },
+  };
+
+  let value = object.get_string().unwrap().get_value();
+
+  // An empty separator splits the characters.
+  let elements: Vec<Box<Objects>> = if separator.is_empty() {
+    value.chars().map(|character| StringO::new(character.to_string())).collect()
+  } else {
+    value.split(separator.as_str()).map(|element| StringO::new(element.to_string())).collect()
+  };
+
+  Array::new(elements)
+}
@@ -2,6 +2,14 @@ mod call;
 mod hashmap;
 mod infix;
 mod prefix;
+mod suffix;
+mod template;
+
+pub use call::call_anonymous_function;
+pub use infix::{
+  equal_objects,
+  object_is_type,
+};
 
 use crate::{
   compiler::{
@@ -10,9 +18,9 @@ use crate::{
     Boolean,
     builtins::get_builtin_for_identifier,
     Error,
+    get_index,
     Null,
     Number,
-    Object,
     Objects,
     StringO,
   },
@@ -65,6 +73,7 @@ pub fn evaluate_expression(
       anonymous_function.get_type(),
       anonymous_function.get_body(),
       environment.store.clone(),
+      environment.running_file.clone(),
     );
 
     return object;
@@ -110,40 +119,21 @@ pub fn evaluate_expression(
     }
 
     // Get string value.
-    if identifier_obj.get_string().is_some() && index_obj.get_number().is_some() {
-      let index: usize;
-      let string: String = identifier_obj.get_string().unwrap().get_value();
-      let value: String = index_obj.get_number().unwrap().string();
-
-      if value == "-1" {
-        index = string.len() - 1;
-      } else {
-        index = value.parse().unwrap();
-      }
-
-      if index >= string.len() {
-        return Null::new();
-      }
-
-      return StringO::new(string[index..index + 1].to_string());
+    if let Some(string) = identifier_obj.get_string() {
+      // The index counts the characters, not the bytes.
+      let characters: Vec<char> = string.get_value().chars().collect();
+
+      return match get_index(&index_obj, characters.len()).and_then(|index| characters.get(index)) {
+        Some(character) => StringO::new(character.to_string()),
+        None => Null::new(),
+      };
     }
     // Get array value.
-    else if identifier_obj.get_array().is_some() && index_obj.get_number().is_some() {
-      let index: usize;
-      let elements = identifier_obj.get_array().unwrap().get_elements();
-      let value = index_obj.get_number().unwrap().string();
-
-      if value == "-1" {
-        index = elements.len() - 1;
-      } else {
-        index = value.parse().unwrap();
-      }
-
-      if index >= elements.len() {
-        return Null::new();
-      }
-
-      return elements[index].clone();
+    else if let Some(array) = identifier_obj.get_array() {
+      return match get_index(&index_obj, array.get_length()).and_then(|index| array.get_element(index)) {
+        Some(element) => element,
+        None => Null::new(),
+      };
     }
   }
 
@@ -195,6 +185,16 @@ pub fn evaluate_expression(
     return StringO::new(string.get_value()[1..string.get_value().len() - 1].to_string());
   }
 
+  // Suffix
+  if let Some(suffix_exp) = expression.get_suffix() {
+    return suffix::evaluate(&suffix_exp, environment);
+  }
+
+  // Template
+  if let Some(template_exp) = expression.get_template() {
+    return template::evaluate(template_exp, environment);
+  }
+
   // Default
   Error::new(
     String::from("is not a valid expression."),
@@ -1,8 +1,13 @@
 use crate::{
   compiler::{
+    AnonymousFunction,
     builtins::get_builtin_for_identifier,
     Error,
+    evaluate_expression,
     evaluate_statement,
+    Frame,
+    Instance,
+    Null,
     Objects,
   },
   Environment,
@@ -13,10 +18,66 @@ use sflyn_parser::{
   Argument,
   Call,
   Expression,
+  tokens::Token,
 };
 
 use super::evaluate_expressions;
 
+/// Call an anonymous function with the evaluated arguments.
+pub fn call_anonymous_function(
+  anonymous_function: AnonymousFunction,
+  arguments: Vec<Box<Objects>>,
+  token: Token,
+  environment: &mut Environment,
+) -> Box<Objects> {
+  let frame = Frame::new(token.value.clone(), token.clone(), environment.running_file.clone());
+
+  // Run the function in a new scope of the captured one, and restore the caller scope after it.
+  let store = std::mem::replace(&mut environment.store, Store::from_store(anonymous_function.store));
+  let running_file = std::mem::replace(&mut environment.running_file, anonymous_function.file.clone());
+
+  environment.frames.push(frame.clone());
+
+  // Add call arguments to the function scope.
+  for (index, argument) in arguments.into_iter().enumerate() {
+    let function_argument: Argument = anonymous_function.arguments[index].get_argument().unwrap();
+
+    // Check if the argument is a const.
+    if function_argument.is_const() {
+      environment.store.set_const(function_argument.get_token().value, function_argument.get_token());
+    }
+
+    let argument = environment.store.with_optionals(argument, &function_argument.get_type());
+
+    environment.store.set_object(function_argument.get_token().value, argument);
+  }
+
+  let result = evaluate_statement(&anonymous_function.body, environment);
+
+  environment.store = store;
+  environment.running_file = running_file;
+  environment.frames.pop();
+
+  let object = match result {
+    // Unwrap the returned object, so it is not returned again by the caller function.
+    Some(object) => match object.get_return() {
+      Some(return_o) => return_o.get_value(),
+      None => object,
+    },
+    None => Error::new(
+      String::from("unknown statement"),
+      anonymous_function.body.token(),
+    ),
+  };
+
+  // Add the function call to the error trace.
+  if let Some(error) = object.get_error() {
+    return error.add_frame(frame, anonymous_function.file);
+  }
+
+  object
+}
+
 pub fn evaluate(
   call: Call,
   environment: &mut Environment,
@@ -42,28 +103,71 @@ pub fn evaluate(
 
   // Check if the function object is an anonymous function.
   if let Some(anonymous_function) = function_object.get_anonymous_function() {
-    let mut index: usize = 0;
+    return call_anonymous_function(anonymous_function, arguments, call.get_token(), environment);
+  }
+  // Check if the function object is a class.
+  else if let Some(class) = function_object.get_class() {
+    let mut class_environment = environment.clone();
+
+    class_environment.store = Store::from_store(class.store.clone());
+    class_environment.running_file = class.file.clone();
+
+    // Add the constructor call to the error trace.
+    let frame = Frame::new(call.get_token().value, call.get_token(), environment.running_file.clone());
 
-    let mut function_environment = environment.clone();
+    let object = Instance::new(class.clone());
+    let instance = object.get_instance().unwrap();
+
+    // Add the constructor arguments to the instance.
+    for (index, class_argument) in class.arguments.iter().enumerate() {
+      let class_argument: Argument = class_argument.get_argument().unwrap();
+
+      let value = match arguments.get(index) {
+        Some(argument) => argument.clone(),
+        None => match class_argument.get_value() {
+          Some(expression) => evaluate_expression(&expression, &mut class_environment),
+          None => Error::new(
+            format!("`{}` argument not found.", class_argument.get_token().value),
+            call.get_token(),
+          ),
+        },
+      };
+
+      // Check if the value object is an error.
+      if let Some(error) = value.get_error() {
+        return error.add_frame(frame, class.file.clone());
+      }
+
+      let value = class_environment.store.with_optionals(value, &class_argument.get_type());
+
+      // Check if the argument is a const.
+      if class_argument.is_const() {
+        class_environment.store.set_const(class_argument.get_token().value, class_argument.get_token());
+      }
+
+      class_environment.store.set_object(class_argument.get_token().value, value.clone());
+      instance.set_field(class_argument.get_token().value, value);
+    }
 
-    function_environment.store = Store::from_store(anonymous_function.store);
+    class_environment.store.set_object(String::from("this"), object.clone());
 
-    // Add call arguments to the function environment.
-    for argument in arguments {
-      let function_argument: Argument = anonymous_function.arguments[index].get_argument().unwrap();
+    // Add the fields to the instance.
+    for field in class.fields.iter() {
+      let value = match field.get_value() {
+        Some(expression) => evaluate_expression(&expression, &mut class_environment),
+        None => Null::new(),
+      };
 
-      function_environment.store.set_object(function_argument.get_token().value, argument);
+      // Check if the value object is an error.
+      if let Some(error) = value.get_error() {
+        return error.add_frame(frame, class.file.clone());
+      }
 
-      index += 1;
+      class_environment.store.set_object(field.get_name().value, value.clone());
+      instance.set_field(field.get_name().value, value);
     }
 
-    return match evaluate_statement(&anonymous_function.body, &mut function_environment) {
-      Some(object) => object,
-      None => Error::new(
-        format!("unknown statement"),
-        anonymous_function.body.token(),
-      ),
-    };
+    return object;
   }
   // Check if the function object is a builtin.
   else if let Some(builtin) = function_object.get_builtin() {
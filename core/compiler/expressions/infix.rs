@@ -1,10 +1,18 @@
 use crate::{
   compiler::{
+    AnonymousFunction,
     Array,
     Boolean,
+    builtins::{
+      get_builtin_member,
+      Members,
+    },
+    Class,
     Error,
     ForIn,
     ForOf,
+    get_index,
+    Instance,
     Number,
     Objects,
     StringO,
@@ -15,16 +23,212 @@ use crate::{
 
 use sflyn_parser::{
   Expression,
+  Expressions,
   Infix,
   tokens::{
     Keywords,
     Signs,
+    Token,
     Types,
   },
 };
 
 use super::evaluate_expression;
 
+/// Compare two objects and get the result as a boolean object.
+pub fn equal_objects(
+  left_object: Box<Objects>,
+  right_object: Box<Objects>,
+) -> Box<Objects> {
+  Boolean::new(left_object == right_object)
+}
+
+/// Add the instance fields and methods to the environment.
+fn set_instance_members(
+  instance: Instance,
+  environment: &mut Environment,
+) {
+  for item in instance.get_data() {
+    environment.store.set_object(item.key, item.value);
+  }
+
+  let class = instance.class.clone();
+
+  for method in class.methods.iter() {
+    let mut method_environment = environment.clone();
+
+    // The instance fields are shared with the method, so the changes are kept in the instance.
+    method_environment.store = Store::from_instance(class.store.clone(), instance.clone());
+
+    // Add the const class arguments to the method.
+    for argument in class.arguments.iter() {
+      if let Some(argument) = argument.get_argument() {
+        if argument.is_const() {
+          method_environment.store.set_const(argument.get_token().value, argument.get_token());
+        }
+      }
+    }
+
+    // Bind `this` and the class to the method.
+    method_environment.store.set_object(String::from("this"), Box::new(Objects::INSTANCE(instance.clone())));
+    method_environment.store.set_object(
+      class.name.clone(),
+      Class::new(
+        class.name.clone(),
+        class.arguments.clone(),
+        class.fields.clone(),
+        class.methods.clone(),
+        class.store.clone(),
+        class.file.clone(),
+      ),
+    );
+
+    AnonymousFunction::add_arguments_to_environment(
+      method.get_arguments(),
+      &mut method_environment,
+    );
+
+    environment.store.set_object(
+      method.get_name().value,
+      AnonymousFunction::new(
+        true,
+        method.get_arguments(),
+        method.get_type(),
+        method.get_body(),
+        method_environment.store,
+        class.file.clone(),
+      ),
+    );
+  }
+}
+
+/// Set an instance field from a `instance->field` expression.
+fn set_instance_field(
+  left: &Expressions,
+  value: Box<Objects>,
+  environment: &mut Environment,
+) -> Option<Box<Objects>> {
+  if let Some(method) = left.get_infix() {
+    if method.is_method() {
+      if let Some(identifier) = method.get_right().and_then(|right| right.get_identifier()) {
+        if let Some(instance) = evaluate_expression(&method.get_left(), environment).get_instance() {
+          instance.set_field(identifier.get_value(), value.clone());
+          return Some(value);
+        }
+      }
+    }
+  }
+
+  None
+}
+
+/// Set the value of a variable, an instance field or an array element, and get the new object.
+pub fn set_variable(
+  left: &Expressions,
+  value: Box<Objects>,
+  environment: &mut Environment,
+) -> Option<Box<Objects>> {
+  if let Some(identifier) = left.get_identifier() {
+    environment.store.update_object(identifier.get_value(), value.clone());
+    return Some(value);
+  } else if let Some(object) = set_instance_field(left, value.clone(), environment) {
+    return Some(object);
+  } else if let Some(array_index) = left.get_array_index() {
+    if let Some(env_obj) = environment.store.get_object(&array_index.get_token().value) {
+      if let Some(array_obj) = env_obj.get_array() {
+        let mut elements: Vec<Box<Objects>> = array_obj.get_elements();
+        let index_obj = evaluate_expression(&array_index.get_index(), environment);
+
+        // Check if the index object is an error.
+        if index_obj.get_error().is_some() {
+          return Some(index_obj);
+        }
+
+        // Throw a catchable error when the index is out of the array.
+        let index: usize = match get_index(&index_obj, elements.len()) {
+          Some(index) if index < elements.len() => index,
+          _ => {
+            return Some(Error::new(
+              format!("`{}` is out of the array range.", index_obj.string()),
+              array_index.get_index().token(),
+            ));
+          },
+        };
+
+        elements[index] = value;
+
+        let new_array = Array::new(elements);
+
+        environment.store.update_object(array_index.get_token().value, new_array.clone());
+
+        return Some(new_array);
+      }
+    }
+  }
+
+  None
+}
+
+/// Evaluate the native member of the object, a property like `length` or a method call like `split()`.
+fn evaluate_builtin_member(
+  infix: &Infix,
+  object: &Objects,
+  environment: &mut Environment,
+) -> Option<Box<Objects>> {
+  let right = infix.get_right().unwrap();
+
+  // Check if the member is a method call.
+  if let Some(call) = right.get_call() {
+    let method = match get_builtin_member(object, &call.get_token().value) {
+      Some(Members::METHOD(method)) => method,
+      _ => {
+        return None;
+      },
+    };
+
+    let mut arguments: Vec<Box<Objects>> = Vec::new();
+
+    for argument in call.get_arguments().iter() {
+      let argument = evaluate_expression(argument, environment);
+
+      // Check if the argument is an error.
+      if argument.get_error().is_some() {
+        return Some(argument);
+      }
+
+      arguments.push(argument);
+    }
+
+    return Some((method)(call.get_token(), object, arguments));
+  }
+
+  match get_builtin_member(object, &right.token().value) {
+    Some(Members::PROPERTY(property)) => Some((property)(object)),
+    _ => None,
+  }
+}
+
+/// Check if the object is of the data type.
+pub fn object_is_type(object: &Objects, data_type: Types) -> bool {
+  // Check if the data type is an union.
+  if let Some(union) = data_type.get_union() {
+    for union_type in union.get_types().iter() {
+      if let Some(union_type) = union_type.token.get_type() {
+        if object_is_type(object, union_type) {
+          return true;
+        }
+      }
+    }
+
+    return false;
+  }
+
+  (object.get_boolean().is_some() && data_type == Types::BOOLEAN) ||
+  (object.get_null().is_some() && data_type == Types::NULL) ||
+  (object.get_number().is_some() && data_type == Types::NUMBER) ||
+  (object.get_string().is_some() && data_type == Types::STRING)
+}
+
 pub fn evaluate(
   infix: &Infix,
   environment: &mut Environment,
@@ -68,96 +272,90 @@ pub fn evaluate(
     if let Some(return_o) = obj.get_return() {
       left_object = Some(return_o.get_value());
     }
-  }
 
-  // Create a new environment.
-  let mut right_environment = environment.clone();
+    // Skip the right expression when the left object decides the condition.
+    if let Some(boolean) = left_object.clone().unwrap().get_boolean() {
+      if (infix.get_token().token.expect_sign(&Signs::AND) && !boolean.get_value()) ||
+        (infix.get_token().token.expect_sign(&Signs::OR) && boolean.get_value()) {
+        return left_object.unwrap();
+      }
+    }
+  }
 
-  // Set the new store.
-  right_environment.store = Store::from_store(environment.store.clone());
+  // Create a new scope for the right expression.
+  let mut right_store = Store::from_store(environment.store.clone());
 
   // Check if the infix is a method.
   if infix.is_method() && left_object.clone().is_some() {
     let left_object: Box<Objects> = left_object.clone().unwrap();
-    let mut name = "";
+
+    // Check if the member is a native member of the left object, like `length` or `split()`.
+    if let Some(object) = evaluate_builtin_member(infix, &left_object, environment) {
+      return object;
+    }
 
     // Check if the left object is a hashmap.
     if let Some(hashmap) = left_object.get_hashmap() {
       // Set the data keys to the new environment.
       for item in hashmap.get_data() {
-        right_environment.store.set_object(item.key, item.value);
+        right_store.set_object(item.key, item.value);
       }
-    } else if left_object.get_number().is_some() {
-      name = "Number";
-    } else if left_object.get_boolean().is_some() {
-      name = "Boolean";
-    } else if left_object.get_array().is_some() {
-      name = "Array";
-    } else if left_object.get_null().is_some() {
-      name = "Null";
-    } else if left_object.get_string().is_some() {
-      name = "String";
     }
+    // Check if the left object is a class instance.
+    else if let Some(instance) = left_object.get_instance() {
+      let store = std::mem::replace(&mut environment.store, right_store.clone());
 
-    if !name.is_empty() {
-      // Get the object from the environment.
-      if let Some(obj) = environment.store.get_object(&name.to_string()) {
-        if let Some(hashmap) = obj.get_hashmap() {
-          // Set the data keys to the new environment.
-          for item in hashmap.get_data() {
-            right_environment.store.set_object(item.key, item.value);
-          }
+      set_instance_members(instance, environment);
+
+      environment.store = store;
+    }
+    // Check if the left object is an enum.
+    else if let Some(enum_o) = left_object.get_enum() {
+      for variant in enum_o.get_variants() {
+        if let Some(enum_variant) = variant.get_enum_variant() {
+          right_store.set_object(enum_variant.name, variant);
         }
       }
     }
-  }
-
-  if left_object.clone().is_some() &&
-    left_object.clone().unwrap().get_string().is_some() &&
-    infix.get_right().is_some() &&
-    infix.get_right().unwrap().token().value == "split" &&
-    infix.get_right().unwrap().get_call().is_some() {
-    let call_right = infix.get_right().unwrap().get_call().unwrap();
-    let arguments = call_right.get_arguments();
-
-    if arguments.len() == 1 {
-      let arg = arguments[0].clone();
-
-      if let Some(string) = arg.get_string() {
-        let split_value = string.get_value();
-        let elements: Vec<Box<Objects>> = left_object.unwrap()
-          .get_string().unwrap().get_value()
-          .split(&split_value[1..split_value.len() - 1])
-          .map(|x| StringO::new(x.to_string()))
-          .collect();
-
-        return Array::new(elements[1..elements.len() - 1].to_vec());
-      }
+    // Check if the left object is an enum variant.
+    else if let Some(enum_variant) = left_object.get_enum_variant() {
+      right_store.set_object(String::from("name"), StringO::new(enum_variant.name));
+      right_store.set_object(String::from("value"), enum_variant.value);
     }
   }
 
   if infix.is_type() {
     let right_type = infix.get_right_type().unwrap();
 
-    if let Some(data_type) = right_type.token.get_type() {
+    // Check if the right type is an enum or a class name.
+    if right_type.token.is_identifier() {
       let left_object: Box<Objects> = left_object.unwrap();
 
-      return Boolean::new(
-        (left_object.get_boolean().is_some() && data_type == Types::BOOLEAN) ||
-        (left_object.get_null().is_some() && data_type == Types::NULL) ||
-        (left_object.get_number().is_some() && data_type == Types::NUMBER) ||
-        (left_object.get_string().is_some() && data_type == Types::STRING)
-      );
+      if let Some(enum_variant) = left_object.get_enum_variant() {
+        return Boolean::new(enum_variant.enum_name == right_type.value);
+      } else if let Some(instance) = left_object.get_instance() {
+        return Boolean::new(instance.class.name == right_type.value);
+      }
+
+      return Boolean::new(false);
+    }
+
+    if let Some(data_type) = right_type.token.get_type() {
+      return Boolean::new(object_is_type(&left_object.unwrap(), data_type));
     }
 
     return Boolean::new(false);
   } else {
-    // Evaluate right expression.
+    // Evaluate right expression in its scope.
+    let store = std::mem::replace(&mut environment.store, right_store);
+
     let mut right_object: Box<Objects> = evaluate_expression(
       &infix.get_right().unwrap(),
-      &mut right_environment,
+      environment,
     );
 
+    environment.store = store;
+
     // Check if the right object is an error.
     if right_object.get_error().is_some() {
       return right_object;
@@ -170,28 +368,6 @@ pub fn evaluate(
 
     // Parse method.
     if infix.is_method() && left_object.is_some() {
-      let left_object = left_object.unwrap();
-      let right_token = infix.get_right().unwrap().token();
-
-      // Check if the method is 'toString()'.
-      if right_token.value == "toString" && (
-        left_object.get_string().is_some() ||
-        left_object.get_number().is_some() ||
-        left_object.get_boolean().is_some() ||
-        left_object.get_array().is_some()
-      ) {
-        return StringO::new(left_object.string());
-      }
-      // Check if the method is 'length' in a string.
-      else if right_token.value == "length" && left_object.get_string().is_some() {
-        return Number::new(
-          left_object
-            .get_string().unwrap()
-            .get_value().len()
-            .to_string().parse().unwrap()
-        );
-      }
-
       return right_object;
     }
     // Parse infix without 'in' or 'of'.
@@ -229,11 +405,11 @@ pub fn evaluate(
       }
       // Check if the operator is an equal sign.
       else if infix.get_token().token.expect_sign(&Signs::EQUAL) {
-        return Boolean::new(left_object == right_object);
+        return equal_objects(left_object, right_object);
       }
       // Check if the operator is a not equal sign.
       else if infix.get_token().token.expect_sign(&Signs::NOTEQUAL) {
-        return Boolean::new(left_object != right_object);
+        return Boolean::new(!Boolean::is_truthy(equal_objects(left_object, right_object)));
       }
       // Check if the operator is an or sign.
       else if infix.get_token().token.expect_sign(&Signs::OR) {
@@ -280,9 +456,19 @@ pub fn evaluate(
             right_object.get_array().unwrap().get_elements(),
           );
         }
+        // Check if the right object is an enum.
+        else if let Some(enum_o) = right_object.get_enum() {
+          let mut name: String = String::new();
+
+          if let Some(identifier) = infix.get_left().get_identifier() {
+            name = identifier.get_value();
+          }
+
+          return ForIn::new(name, enum_o.get_variants());
+        }
 
         return Error::new(
-          String::from("expect an array expression."),
+          String::from("expect an array or an enum expression."),
           infix.get_right().unwrap().token(),
         );
       }
@@ -323,114 +509,58 @@ pub fn evaluate(
     }
     // Check if is a variable set.
     else if infix.is_variable_set() && left_object.is_some() {
-      if infix.get_token().token.expect_sign(&Signs::ASSIGN) {
-        if let Some(identifier) = infix.get_left().get_identifier() {
-          environment.store.set_object(identifier.get_value(), right_object.clone());
-          return right_object;
-        } else if let Some(array_index) = infix.get_left().get_array_index() {
-          if let Some(env_obj) = environment.store.get_object(&array_index.get_token().value) {
-            if let Some(array_obj) = env_obj.get_array() {
-              let mut index: usize = 0;
-              let mut elements: Vec<Box<Objects>> = array_obj.get_elements();
-
-              if let Some(number) = array_index.get_index().get_number() {
-                index = number.string().parse().unwrap();
-              } else if let Some(prefix) = array_index.get_index().get_prefix() {
-                if prefix.string() == "-1" {
-                  index = elements.len() - 1;
-                }
-              }
-
-              elements[index] = right_object;
+      let mut name = Token::new_empty();
 
-              let new_array = Array::new(elements);
+      if let Some(identifier) = infix.get_left().get_identifier() {
+        name = identifier.get_token();
+      } else if let Some(array_index) = infix.get_left().get_array_index() {
+        name = array_index.get_token();
+      }
 
-              environment.store.set_object(array_index.get_token().value, new_array.clone());
+      // Check if the variable is a const.
+      if let Some(declaration) = environment.store.get_const(&name.value) {
+        return Error::new_with_label(
+          format!("`{}` is a const.", name.value),
+          name,
+          String::from("declared as a const here."),
+          declaration,
+        );
+      }
 
-              return new_array;
-            }
-          }
+      if infix.get_token().token.expect_sign(&Signs::ASSIGN) {
+        if let Some(object) = set_variable(&infix.get_left(), right_object.clone(), environment) {
+          return object;
+        }
+      } else if let Some(number_o) = left_object.clone().unwrap().get_number() {
+        let mut value: f64 = number_o.get_value();
+        let number_two: f64 = right_object.get_number().unwrap().get_value();
+
+        if infix.get_token().token.expect_sign(&Signs::PLUSASSIGN) {
+          value += number_two;
+        } else if infix.get_token().token.expect_sign(&Signs::MINUSASSIGN) {
+          value -= number_two;
+        } else if infix.get_token().token.expect_sign(&Signs::MULTIPLYASSIGN) {
+          value *= number_two;
+        } else if infix.get_token().token.expect_sign(&Signs::DIVIDEASSIGN) {
+          value /= number_two;
+        } else if infix.get_token().token.expect_sign(&Signs::MODULEASSIGN) {
+          value %= number_two;
         }
-      } else {
-        if let Some(number_o) = left_object.clone().unwrap().get_number() {
-          let mut value: f64 = number_o.get_value();
-          let number_two: f64 = right_object.get_number().unwrap().get_value();
-
-          if infix.get_token().token.expect_sign(&Signs::PLUSASSIGN) {
-            value += number_two;
-          } else if infix.get_token().token.expect_sign(&Signs::MINUSASSIGN) {
-            value -= number_two;
-          } else if infix.get_token().token.expect_sign(&Signs::MULTIPLYASSIGN) {
-            value *= number_two;
-          } else if infix.get_token().token.expect_sign(&Signs::DIVIDEASSIGN) {
-            value /= number_two;
-          }
-
-          let new_object = Number::new(value);
-
-          if let Some(identifier) = infix.get_left().get_identifier() {
-            environment.store.set_object(identifier.get_value(), new_object.clone());
-            return new_object;
-          } else if let Some(array_index) = infix.get_left().get_array_index() {
-            if let Some(env_obj) = environment.store.get_object(&array_index.get_token().value) {
-              if let Some(array_obj) = env_obj.get_array() {
-                let mut index: usize = 0;
-                let mut elements: Vec<Box<Objects>> = array_obj.get_elements();
-
-                if let Some(number) = array_index.get_index().get_number() {
-                  index = number.string().parse().unwrap();
-                } else if let Some(prefix) = array_index.get_index().get_prefix() {
-                  if prefix.string() == "-1" {
-                    index = elements.len() - 1;
-                  }
-                }
-
-                elements[index] = new_object;
-
-                let new_array = Array::new(elements);
-
-                environment.store.set_object(array_index.get_token().value, new_array.clone());
-
-                return new_array;
-              }
-            }
-          }
-        } else if let Some(string_o) = left_object.clone().unwrap().get_string() {
-          let new_object = StringO::new(
-            format!(
-              "{}{}",
-              string_o.get_value(),
-              right_object.get_string().unwrap().get_value(),
-            ),
-          );
-
-          if let Some(identifier) = infix.get_left().get_identifier() {
-            environment.store.set_object(identifier.get_value(), new_object.clone());
-            return new_object;
-          } else if let Some(array_index) = infix.get_left().get_array_index() {
-            if let Some(env_obj) = environment.store.get_object(&array_index.get_token().value) {
-              if let Some(array_obj) = env_obj.get_array() {
-                let mut index: usize = 0;
-                let mut elements: Vec<Box<Objects>> = array_obj.get_elements();
-
-                if let Some(number) = array_index.get_index().get_number() {
-                  index = number.string().parse().unwrap();
-                } else if let Some(prefix) = array_index.get_index().get_prefix() {
-                  if prefix.string() == "-1" {
-                    index = elements.len() - 1;
-                  }
-                }
-
-                elements[index] = new_object;
-
-                let new_array = Array::new(elements);
 
-                environment.store.set_object(array_index.get_token().value, new_array.clone());
+        if let Some(object) = set_variable(&infix.get_left(), Number::new(value), environment) {
+          return object;
+        }
+      } else if let Some(string_o) = left_object.clone().unwrap().get_string() {
+        let new_object = StringO::new(
+          format!(
+            "{}{}",
+            string_o.get_value(),
+            right_object.get_string().unwrap().get_value(),
+          ),
+        );
 
-                return new_array;
-              }
-            }
-          }
+        if let Some(object) = set_variable(&infix.get_left(), new_object, environment) {
+          return object;
         }
       }
     }
@@ -10,16 +10,79 @@ use crate::{
 
 use sflyn_parser::{
   Expression,
+  Expressions,
   Prefix,
-  tokens::Signs,
+  tokens::{
+    Signs,
+    Token,
+  },
 };
 
-use super::evaluate_expression;
+use super::{
+  evaluate_expression,
+  infix::set_variable,
+};
+
+/// Add or subtract one to a variable (`++` and `--`), and get the old and the new values.
+pub fn evaluate_increment(
+  left: &Box<Expressions>,
+  token: Token,
+  environment: &mut Environment,
+) -> Result<(Box<Objects>, Box<Objects>), Box<Objects>> {
+  // Check if the variable is a const.
+  if let Some(identifier) = left.get_identifier() {
+    if let Some(declaration) = environment.store.get_const(&identifier.get_value()) {
+      return Err(Error::new_with_label(
+        format!("`{}` is a const.", identifier.get_value()),
+        identifier.get_token(),
+        String::from("declared as a const here."),
+        declaration,
+      ));
+    }
+  }
+
+  let object = evaluate_expression(left, environment);
+
+  // Check if the object is an error.
+  if object.get_error().is_some() {
+    return Err(object);
+  }
+
+  let value: f64 = match object.get_number() {
+    Some(number) => number.get_value(),
+    None => {
+      return Err(Error::new(
+        format!("only can use the `{}` operator in number type.", token.value),
+        token,
+      ));
+    },
+  };
+
+  let new_object = Number::new(if token.token.expect_sign(&Signs::PLUSPLUS) { value + 1.0 } else { value - 1.0 });
+
+  match set_variable(left, new_object.clone(), environment) {
+    Some(result) if result.get_error().is_some() => Err(result),
+    Some(_) => Ok((object, new_object)),
+    None => Err(Error::new(
+      String::from("is not a valid variable."),
+      left.token(),
+    )),
+  }
+}
 
 pub fn evaluate(
   prefix: &Prefix,
   environment: &mut Environment,
 ) -> Box<Objects> {
+  // Check if the operator changes the variable.
+  if prefix.get_token().token.expect_sign(&Signs::PLUSPLUS) ||
+    prefix.get_token().token.expect_sign(&Signs::MINUSMINUS) {
+    return match evaluate_increment(&prefix.get_right(), prefix.get_token(), environment) {
+      Ok((_, new_object)) => new_object,
+      Err(error) => error,
+    };
+  }
+
   // Evaluate right expression.
   let right_object = evaluate_expression(&prefix.get_right(), environment);
 
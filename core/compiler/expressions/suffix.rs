@@ -0,0 +1,34 @@
+use crate::{
+  compiler::{
+    Error,
+    Objects,
+  },
+  Environment,
+};
+
+use sflyn_parser::{
+  Expression,
+  Suffix,
+  tokens::Signs,
+};
+
+use super::prefix::evaluate_increment;
+
+pub fn evaluate(
+  suffix: &Suffix,
+  environment: &mut Environment,
+) -> Box<Objects> {
+  // Check if the operator is an increment or a decrement, they return the old value.
+  if suffix.get_token().token.expect_sign(&Signs::PLUSPLUS) ||
+    suffix.get_token().token.expect_sign(&Signs::MINUSMINUS) {
+    return match evaluate_increment(&suffix.get_left(), suffix.get_token(), environment) {
+      Ok((object, _)) => object,
+      Err(error) => error,
+    };
+  }
+
+  Error::new(
+    format!("Unknown suffix: {}", suffix.string()),
+    suffix.get_token(),
+  )
+}
@@ -0,0 +1,32 @@
+use crate::{
+  compiler::{
+    Objects,
+    StringO,
+  },
+  Environment,
+};
+
+use sflyn_parser::Template;
+
+use super::evaluate_expression;
+
+pub fn evaluate(
+  template: Template,
+  environment: &mut Environment,
+) -> Box<Objects> {
+  let mut value = String::new();
+
+  for part in template.get_parts().iter() {
+    // Compile the part.
+    let object = evaluate_expression(part, environment);
+
+    // Check if the object is an error.
+    if object.get_error().is_some() {
+      return object;
+    }
+
+    value.push_str(&object.string());
+  }
+
+  StringO::new(value)
+}
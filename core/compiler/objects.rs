@@ -2,7 +2,10 @@ mod anonymous_function;
 mod array;
 mod boolean;
 mod builtin;
+mod class;
+mod closure;
 mod continue_break;
+mod enum_o;
 mod error;
 mod for_in;
 mod for_of;
@@ -17,7 +20,10 @@ pub use anonymous_function::*;
 pub use array::*;
 pub use boolean::*;
 pub use builtin::*;
+pub use class::*;
+pub use closure::*;
 pub use continue_break::*;
+pub use enum_o::*;
 pub use error::*;
 pub use for_in::*;
 pub use for_of::*;
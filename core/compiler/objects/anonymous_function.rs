@@ -24,6 +24,7 @@ pub struct AnonymousFunction {
   pub data_type: Token,
   pub body: Box<Statements>,
   pub store: Store,
+  pub file: String,
 }
 
 impl Object for AnonymousFunction {
@@ -65,6 +66,11 @@ impl AnonymousFunction {
       if let Some(expression) = function_argument.get_value() {
         let object = evaluate_expression(&expression, environment);
 
+        // Check if the argument is a const.
+        if function_argument.is_const() {
+          environment.store.set_const(function_argument.get_token().value, function_argument.get_token());
+        }
+
         environment.store.set_object(function_argument.get_token().value, object);
       }
     }
@@ -76,6 +82,7 @@ impl AnonymousFunction {
     data_type: Token,
     body: Box<Statements>,
     store: Store,
+    file: String,
   ) -> Box<Objects> {
     Box::new(Objects::ANONYMOUSFUNCTION(AnonymousFunction {
       has_function,
@@ -83,6 +90,7 @@ impl AnonymousFunction {
       data_type,
       body,
       store,
+      file,
     }))
   }
 }
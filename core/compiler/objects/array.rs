@@ -3,16 +3,22 @@ use super::{
   Objects,
 };
 
+use std::{
+  cell::RefCell,
+  rc::Rc,
+};
+
+/// An array, shared by the clones, so the methods like `push` change it for every variable.
 #[derive(Debug, Clone, PartialEq)]
 pub struct Array {
-  elements: Vec<Box<Objects>>,
+  elements: Rc<RefCell<Vec<Box<Objects>>>>,
 }
 
 impl Object for Array {
   fn string(&self) -> String {
     let mut elements: Vec<String> = Vec::new();
 
-    for element in self.elements.iter() {
+    for element in self.elements.borrow().iter() {
       elements.push(element.string());
     }
 
@@ -22,10 +28,42 @@ impl Object for Array {
 
 impl Array {
   pub fn new(elements: Vec<Box<Objects>>) -> Box<Objects> {
-    Box::new(Objects::ARRAY(Array { elements }))
+    Box::new(Objects::ARRAY(Array {
+      elements: Rc::new(RefCell::new(elements)),
+    }))
   }
 
   pub fn get_elements(&self) -> Vec<Box<Objects>> {
-    self.elements.clone()
+    self.elements.borrow().clone()
+  }
+
+  pub fn get_element(&self, index: usize) -> Option<Box<Objects>> {
+    self.elements.borrow().get(index).cloned()
+  }
+
+  pub fn get_length(&self) -> usize {
+    self.elements.borrow().len()
+  }
+
+  /// Add an element to the end and get the new length.
+  pub fn push_element(&self, element: Box<Objects>) -> usize {
+    let mut elements = self.elements.borrow_mut();
+
+    elements.push(element);
+    elements.len()
+  }
+}
+
+/// Get the position of an array or a string index, `-1` is the last one. The fractional numbers
+/// and the other negative ones are not positions.
+pub fn get_index(index: &Objects, length: usize) -> Option<usize> {
+  let value = index.get_number()?.get_value();
+
+  if value == -1.0 {
+    length.checked_sub(1)
+  } else if value >= 0.0 && value.fract() == 0.0 {
+    Some(value as usize)
+  } else {
+    None
   }
 }
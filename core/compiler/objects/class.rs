@@ -0,0 +1,155 @@
+use crate::Store;
+
+use sflyn_parser::{
+  Expressions,
+  Function,
+  Variable,
+};
+
+use super::{
+  Closure,
+  HashItem,
+  Object,
+  Objects,
+};
+
+use std::{
+  cell::RefCell,
+  rc::Rc,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Class {
+  pub name: String,
+  pub arguments: Vec<Box<Expressions>>,
+  pub fields: Vec<Variable>,
+  pub methods: Vec<Function>,
+  pub store: Store,
+  pub file: String,
+
+  // Compiled constructor and methods, for the virtual machine.
+  pub constructor: Option<Closure>,
+  pub closures: Vec<(String, Closure)>,
+}
+
+impl Object for Class {
+  fn string(&self) -> String {
+    format!("class {}", self.name)
+  }
+}
+
+impl Class {
+  pub fn new(
+    name: String,
+    arguments: Vec<Box<Expressions>>,
+    fields: Vec<Variable>,
+    methods: Vec<Function>,
+    store: Store,
+    file: String,
+  ) -> Box<Objects> {
+    Box::new(Objects::CLASS(Class {
+      name,
+      arguments,
+      fields,
+      methods,
+      store,
+      file,
+      constructor: None,
+      closures: Vec::new(),
+    }))
+  }
+
+  /// Create a class of the virtual machine, with the compiled constructor and methods.
+  pub fn new_compiled(
+    name: String,
+    constructor: Closure,
+    closures: Vec<(String, Closure)>,
+    file: String,
+  ) -> Box<Objects> {
+    Box::new(Objects::CLASS(Class {
+      name,
+      arguments: Vec::new(),
+      fields: Vec::new(),
+      methods: Vec::new(),
+      store: Store::new(),
+      file,
+      constructor: Some(constructor),
+      closures,
+    }))
+  }
+
+  /// Get the compiled method of the class.
+  pub fn get_closure(&self, name: &str) -> Option<Closure> {
+    self.closures.iter().find(|(key, _)| key == name).map(|(_, closure)| closure.clone())
+  }
+}
+
+#[derive(Debug, Clone)]
+pub struct Instance {
+  pub class: Class,
+  data: Rc<RefCell<Vec<HashItem>>>,
+}
+
+/// Two instances are only equal when they are the same instance.
+impl PartialEq for Instance {
+  fn eq(&self, other: &Self) -> bool {
+    Rc::ptr_eq(&self.data, &other.data)
+  }
+}
+
+impl Object for Instance {
+  fn string(&self) -> String {
+    let mut data: Vec<String> = Vec::new();
+
+    for item in self.data.borrow().iter() {
+      data.push(format!(
+        "{}: {}",
+        item.key,
+        item.value.clone().string(),
+      ));
+    }
+
+    format!(
+      "{} {{ {} }}",
+      self.class.name,
+      data.join(", "),
+    )
+  }
+}
+
+impl Instance {
+  pub fn new(class: Class) -> Box<Objects> {
+    Box::new(Objects::INSTANCE(Instance {
+      class,
+      data: Rc::new(RefCell::new(Vec::new())),
+    }))
+  }
+
+  pub fn get_data(&self) -> Vec<HashItem> {
+    self.data.borrow().clone()
+  }
+
+  pub fn get_field(&self, key: &String) -> Option<Box<Objects>> {
+    for item in self.data.borrow().iter() {
+      if &item.key == key {
+        return Some(item.value.clone());
+      }
+    }
+
+    None
+  }
+
+  /// Set the field value, the change is shared with every copy of the instance.
+  pub fn set_field(&self, key: String, value: Box<Objects>) {
+    let mut data = self.data.borrow_mut();
+
+    for item in data.iter_mut() {
+      if item.key == key {
+        item.value = value;
+        return;
+      }
+    }
+
+    data.push(HashItem { key, value });
+  }
+}
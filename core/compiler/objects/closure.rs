@@ -0,0 +1,48 @@
+use crate::vm::Function;
+
+use std::{
+  cell::RefCell,
+  fmt,
+  rc::Rc,
+};
+
+use super::{
+  Object,
+  Objects,
+};
+
+/// A variable shared between a function and the closures that capture it.
+pub type Cell = Rc<RefCell<Box<Objects>>>;
+
+/// A compiled function with its captured variables.
+#[derive(Clone)]
+pub struct Closure {
+  pub function: Rc<Function>,
+  pub upvalues: Vec<Cell>,
+}
+
+impl fmt::Debug for Closure {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "closure {}", self.function.name)
+  }
+}
+
+impl PartialEq for Closure {
+  fn eq(&self, other: &Self) -> bool {
+    Rc::ptr_eq(&self.function, &other.function) &&
+    self.upvalues.len() == other.upvalues.len() &&
+    self.upvalues.iter().zip(other.upvalues.iter()).all(|(left, right)| Rc::ptr_eq(left, right))
+  }
+}
+
+impl Object for Closure {
+  fn string(&self) -> String {
+    format!("function {}", self.function.name)
+  }
+}
+
+impl Closure {
+  pub fn new(function: Rc<Function>, upvalues: Vec<Cell>) -> Box<Objects> {
+    Box::new(Objects::CLOSURE(Closure { function, upvalues }))
+  }
+}
@@ -0,0 +1,49 @@
+use super::{
+  Object,
+  Objects,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnumVariant {
+  pub enum_name: String,
+  pub name: String,
+  pub value: Box<Objects>,
+}
+
+impl Object for EnumVariant {
+  fn string(&self) -> String {
+    format!("{}.{}", self.enum_name, self.name)
+  }
+}
+
+impl EnumVariant {
+  pub fn new(enum_name: String, name: String, value: Box<Objects>) -> Box<Objects> {
+    Box::new(Objects::ENUMVARIANT(EnumVariant {
+      enum_name,
+      name,
+      value,
+    }))
+  }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnumO {
+  pub name: String,
+  pub variants: Vec<Box<Objects>>,
+}
+
+impl Object for EnumO {
+  fn string(&self) -> String {
+    format!("enum {}", self.name)
+  }
+}
+
+impl EnumO {
+  pub fn new(name: String, variants: Vec<Box<Objects>>) -> Box<Objects> {
+    Box::new(Objects::ENUM(EnumO { name, variants }))
+  }
+
+  pub fn get_variants(&self) -> Vec<Box<Objects>> {
+    self.variants.clone()
+  }
+}
@@ -1,38 +1,166 @@
-use crate::utils::repeat_character;
-
 use sflyn_parser::{
-  File,
+  Codes,
+  Diagnostic,
+  Error as ParserError,
+  Label,
+  Severity,
+  TraceFrame,
   tokens::Token,
 };
 
-use super::Objects;
+use super::{
+  HashItem,
+  HashMap,
+  Objects,
+  StringO,
+};
+
+/// A function call, with the call token and the file where it is.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Frame {
+  pub name: String,
+  pub token: Token,
+  pub file: String,
+}
+
+impl Frame {
+  pub fn new(name: String, token: Token, file: String) -> Frame {
+    Frame { name, token, file }
+  }
+}
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Error {
   message: String,
   token: Token,
+
+  file: Option<String>,
+  frames: Vec<Frame>,
+  labels: Vec<Label>,
 }
 
 impl Error {
   pub fn new(message: String, token: Token) -> Box<Objects> {
-    Box::new(Objects::ERROR(Error { message, token }))
+    Box::new(Objects::ERROR(Error {
+      message,
+      token,
+
+      file: None,
+      frames: Vec::new(),
+      labels: Vec::new(),
+    }))
+  }
+
+  /// Create an error with a secondary span, like the declaration of a value.
+  pub fn new_with_label(message: String, token: Token, label: String, label_token: Token) -> Box<Objects> {
+    Box::new(Objects::ERROR(Error {
+      message,
+      token,
+
+      file: None,
+      frames: Vec::new(),
+      labels: vec![Label::from_token(label, label_token)],
+    }))
   }
 
-  pub fn string(&self, file: File) -> String {
-    if self.token.line < 1 {
-      return self.message.clone();
+  /// Create an error from a thrown value, a string or an `Error` hashmap.
+  pub fn from_object(value: &Objects, token: Token) -> Box<Objects> {
+    let mut message = value.string();
+
+    if let Some(hashmap) = value.get_hashmap() {
+      for item in hashmap.get_data() {
+        if item.key == "message" {
+          message = item.value.string();
+        }
+      }
+    }
+
+    Error::new(message, token)
+  }
+
+  pub fn get_message(&self) -> String {
+    self.message.clone()
+  }
+
+  /// Get the trace like a JavaScript stack, from the innermost function.
+  /// The file is used when the error was raised in the running file, and the running frames
+  /// are the calls until the catch, from the outermost.
+  pub fn get_stack(&self, file: String, running_frames: Vec<Frame>) -> String {
+    let mut stack = format!("Error: {}", self.message);
+
+    let mut frames: Vec<Frame> = self.frames.clone();
+
+    frames.extend(running_frames.into_iter().rev());
+
+    let mut locations: Vec<(String, Token)> = vec![(self.file.clone().unwrap_or(file), self.token.clone())];
+
+    for frame in frames.iter() {
+      locations.push((frame.file.clone(), frame.token.clone()));
+    }
+
+    for (index, (file, token)) in locations.iter().enumerate() {
+      let location = format!("{}:{}:{}", file, token.line, token.position);
+
+      stack.push_str(&match frames.get(index) {
+        Some(frame) => format!("\n    at {} ({})", frame.name, location),
+        None => format!("\n    at {}", location),
+      });
+    }
+
+    stack
+  }
+
+  /// Get the catch error object, a hashmap with the `message` and the `stack`.
+  pub fn get_object(&self, file: String, running_frames: Vec<Frame>) -> Box<Objects> {
+    HashMap::new(vec![
+      HashItem {
+        key: String::from("message"),
+        value: StringO::new(self.get_message()),
+      },
+      HashItem {
+        key: String::from("stack"),
+        value: StringO::new(self.get_stack(file, running_frames)),
+      },
+    ])
+  }
+
+  /// Add the function call where the error was raised, from the innermost.
+  /// The file is where the function is declared, so it is the file of the error token
+  /// when the error does not have one.
+  pub fn add_frame(&self, frame: Frame, file: String) -> Box<Objects> {
+    let mut error = self.clone();
+
+    if error.file.is_none() {
+      error.file = Some(file);
+    }
+
+    error.frames.push(frame);
+
+    Box::new(Objects::ERROR(error))
+  }
+
+  /// Get the runtime error diagnostic.
+  pub fn get_diagnostic(&self) -> Diagnostic {
+    let mut error = if self.token.line < 1 {
+      ParserError::new(self.message.clone(), 0, 0, 0)
+    } else {
+      ParserError::from_token(self.message.clone(), self.token.clone())
+    };
+
+    error.labels = self.labels.clone();
+
+    let mut diagnostic = Diagnostic::from_error(Severity::ERROR, error, Codes::RUNTIME);
+
+    diagnostic.file = self.file.clone();
+
+    for frame in self.frames.iter() {
+      diagnostic.trace.push(TraceFrame::from_token(
+        frame.name.clone(),
+        frame.file.clone(),
+        frame.token.clone(),
+      ));
     }
 
-    let line = file.get_lines()[self.token.line - 1].clone();
-
-    format!(
-      "{} | {}\n{} | {}{} {}",
-      self.token.line,
-      line,
-      repeat_character(self.token.line.to_string().len(), " "),
-      repeat_character(self.token.position - 1, " "),
-      repeat_character(self.token.value.len(), "^"),
-      self.message,
-    )
+    diagnostic
   }
 }
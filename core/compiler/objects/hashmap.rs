@@ -1,4 +1,5 @@
 use super::{
+  Null,
   Object,
   Objects,
 };
@@ -41,4 +42,24 @@ impl HashMap {
   pub fn get_data(&self) -> Vec<HashItem> {
     self.data.clone()
   }
+
+  pub fn get_item(&self, index: usize) -> Option<HashItem> {
+    self.data.get(index).cloned()
+  }
+
+  /// Get a new hashmap with the missing optional members as `null`.
+  pub fn with_optionals(&self, optionals: &[String]) -> Box<Objects> {
+    let mut data: Vec<HashItem> = self.get_data();
+
+    for key in optionals.iter() {
+      if !data.iter().any(|item| &item.key == key) {
+        data.push(HashItem {
+          key: key.clone(),
+          value: Null::new(),
+        });
+      }
+    }
+
+    HashMap::new(data)
+  }
 }
@@ -20,11 +20,16 @@ pub enum Objects {
   BOOLEAN(Boolean),
   BREAK(Break),
   BUILTIN(BuiltIn),
+  CLASS(Class),
+  CLOSURE(Closure),
   CONTINUE(Continue),
+  ENUM(EnumO),
+  ENUMVARIANT(EnumVariant),
   ERROR(Error),
   FORIN(ForIn),
   FOROF(ForOf),
   HASHMAP(HashMap),
+  INSTANCE(Instance),
   NULL(Null),
   NUMBER(Number),
   RETURN(ReturnO),
@@ -74,6 +79,20 @@ impl Objects {
     }
   }
 
+  pub fn get_class(&self) -> Option<Class> {
+    match self {
+      Objects::CLASS(class) => Some(class.clone()),
+      _ => None,
+    }
+  }
+
+  pub fn get_closure(&self) -> Option<Closure> {
+    match self {
+      Objects::CLOSURE(closure) => Some(closure.clone()),
+      _ => None,
+    }
+  }
+
   pub fn get_continue(&self) -> Option<Continue> {
     match self {
       Objects::CONTINUE(continue_o) => Some(continue_o.clone()),
@@ -81,6 +100,20 @@ impl Objects {
     }
   }
 
+  pub fn get_enum(&self) -> Option<EnumO> {
+    match self {
+      Objects::ENUM(enum_o) => Some(enum_o.clone()),
+      _ => None,
+    }
+  }
+
+  pub fn get_enum_variant(&self) -> Option<EnumVariant> {
+    match self {
+      Objects::ENUMVARIANT(enum_variant) => Some(enum_variant.clone()),
+      _ => None,
+    }
+  }
+
   pub fn get_error(&self) -> Option<Error> {
     match self {
       Objects::ERROR(error) => Some(error.clone()),
@@ -109,6 +142,13 @@ impl Objects {
     }
   }
 
+  pub fn get_instance(&self) -> Option<Instance> {
+    match self {
+      Objects::INSTANCE(instance) => Some(instance.clone()),
+      _ => None,
+    }
+  }
+
   pub fn get_null(&self) -> Option<Null> {
     match self {
       Objects::NULL(null) => Some(null.clone()),
@@ -153,10 +193,15 @@ impl Objects {
       Objects::BOOLEAN(boolean) => boolean.string(),
       Objects::BREAK(break_o) => break_o.string(),
       Objects::BUILTIN(builtin) => builtin.string(),
+      Objects::CLASS(class) => class.string(),
+      Objects::CLOSURE(closure) => closure.string(),
       Objects::CONTINUE(continue_o) => continue_o.string(),
+      Objects::ENUM(enum_o) => enum_o.string(),
+      Objects::ENUMVARIANT(enum_variant) => enum_variant.string(),
       Objects::FORIN(for_in) => for_in.string(),
       Objects::FOROF(for_of) => for_of.string(),
       Objects::HASHMAP(hashmap) => hashmap.string(),
+      Objects::INSTANCE(instance) => instance.string(),
       Objects::NULL(null) => null.string(),
       Objects::NUMBER(number) => number.string(),
       Objects::RETURN(return_o) => return_o.string(),
@@ -1,13 +1,19 @@
-mod import;
+pub mod import;
 
 use crate::{
   compiler::{
     AnonymousFunction,
     Break,
     Boolean,
+    Class,
     Continue,
+    EnumO,
+    EnumVariant,
+    equal_objects,
+    Error,
     evaluate_expression,
     Null,
+    Number,
     Objects,
     ReturnO,
     StringO,
@@ -30,6 +36,10 @@ pub fn evaluate_statement(
   if let Some(block) = statement.get_block() {
     let mut result_object: Option<Box<Objects>> = None;
 
+    // Run the block in a new scope, so its variables can shadow the outer ones.
+    let block_store = Store::from_store(environment.store.clone());
+    let store = std::mem::replace(&mut environment.store, block_store);
+
     for statement in block.get_statements().iter() {
       result_object = evaluate_statement(statement, environment);
 
@@ -44,9 +54,26 @@ pub fn evaluate_statement(
       }
     }
 
+    environment.store = store;
+
     return result_object;
   }
 
+  // Class
+  if let Some(class) = statement.get_class() {
+    let object = Class::new(
+      class.get_name().value,
+      class.get_arguments(),
+      class.get_fields(),
+      class.get_methods(),
+      environment.store.clone(),
+      environment.running_file.clone(),
+    );
+
+    // Add class object to the environment.
+    environment.store.set_object(class.get_name().value, object);
+  }
+
   // Continue and break
   if let Some(continue_break) = statement.get_continue_break() {
     if continue_break.get_token().token.expect_keyword(&Keywords::CONTINUE) {
@@ -56,6 +83,66 @@ pub fn evaluate_statement(
     }
   }
 
+  // Do while
+  if let Some(do_while) = statement.get_do_while() {
+    loop {
+      if let Some(obj) = evaluate_statement(&do_while.get_body(), environment) {
+        // Check if the body object is an error or a return.
+        if obj.get_error().is_some() || obj.get_return().is_some() {
+          return Some(obj);
+        }
+
+        if obj.get_break().is_some() {
+          break;
+        }
+      }
+
+      let condition_obj = evaluate_expression(&do_while.get_condition(), environment);
+
+      if condition_obj.get_error().is_some() {
+        return Some(condition_obj);
+      }
+
+      if !Boolean::is_truthy(condition_obj) {
+        break;
+      }
+    }
+
+    return None;
+  }
+
+  // Enum
+  if let Some(enum_s) = statement.get_enum() {
+    let mut variants: Vec<Box<Objects>> = Vec::new();
+    let mut next_value: f64 = 0.0;
+
+    for variant in enum_s.get_variants().iter() {
+      let value: Box<Objects> = match variant.get_value() {
+        Some(expression) => evaluate_expression(&expression, environment),
+        None => Number::new(next_value),
+      };
+
+      // Check if the value object is an error.
+      if value.get_error().is_some() {
+        return Some(value);
+      }
+
+      // The next variant without value continues from this one.
+      if let Some(number) = value.get_number() {
+        next_value = number.get_value() + 1.0;
+      }
+
+      variants.push(EnumVariant::new(
+        enum_s.get_name().value,
+        variant.get_token().value,
+        value,
+      ));
+    }
+
+    // Add enum object to the environment.
+    environment.store.set_object(enum_s.get_name().value, EnumO::new(enum_s.get_name().value, variants));
+  }
+
   // Export
   if let Some(export) = statement.get_export() {
     return evaluate_statement(&export.get_value(), environment);
@@ -83,6 +170,9 @@ pub fn evaluate_statement(
         for obj in for_in.get_elements().iter() {
           let mut new_environment = for_environment.clone();
 
+          // Each iteration has its own scope, so the closures keep their values.
+          new_environment.store = Store::from_store(for_environment.store.clone());
+
           new_environment.store.set_object(for_in.get_name(), obj.clone());
 
           if let Some(obj) = evaluate_statement(&for_s.get_body(), &mut new_environment) {
@@ -105,6 +195,9 @@ pub fn evaluate_statement(
         for item in for_of.get_data().iter() {
           let mut new_environment = for_environment.clone();
 
+          // Each iteration has its own scope, so the closures keep their values.
+          new_environment.store = Store::from_store(for_environment.store.clone());
+
           new_environment.store.set_object(
             for_of.get_names()[0].clone(),
             StringO::new(item.key.clone()),
@@ -146,6 +239,7 @@ pub fn evaluate_statement(
       function.get_type(),
       function.get_body(),
       environment.store.clone(),
+      environment.running_file.clone(),
     );
 
     // Add function object to the environment.
@@ -157,6 +251,11 @@ pub fn evaluate_statement(
     for condition in if_else.get_conditions().iter() {
       let object = evaluate_expression(&condition.get_condition(), environment);
 
+      // Check if the condition object is an error.
+      if object.get_error().is_some() {
+        return Some(object);
+      }
+
       if Boolean::is_truthy(object) {
         return evaluate_statement(&condition.get_consequence(), environment);
       }
@@ -172,6 +271,11 @@ pub fn evaluate_statement(
     return import::evaluate(import_s, environment);
   }
 
+  // Interface
+  if let Some(interface) = statement.get_interface() {
+    environment.store.set_optionals(interface.get_name().value, interface.get_optionals());
+  }
+
   // Return
   if let Some(return_s) = statement.get_return() {
     // Get the return value.
@@ -190,6 +294,95 @@ pub fn evaluate_statement(
     return Some(ReturnO::new(Null::new()));
   }
 
+  // Switch
+  if let Some(switch) = statement.get_switch() {
+    let value_obj = evaluate_expression(&switch.get_value(), environment);
+
+    if value_obj.get_error().is_some() {
+      return Some(value_obj);
+    }
+
+    let mut consequence = switch.get_default();
+
+    'cases: for case in switch.get_cases().iter() {
+      for value in case.get_values().iter() {
+        let case_obj = evaluate_expression(value, environment);
+
+        if case_obj.get_error().is_some() {
+          return Some(case_obj);
+        }
+
+        if Boolean::is_truthy(equal_objects(value_obj.clone(), case_obj)) {
+          consequence = Some(case.get_consequence());
+          break 'cases;
+        }
+      }
+    }
+
+    if let Some(consequence) = consequence {
+      let object = evaluate_statement(&consequence, environment);
+
+      // A break only leaves the switch.
+      if let Some(obj) = object.clone() {
+        if obj.get_break().is_some() {
+          return None;
+        }
+      }
+
+      return object;
+    }
+
+    return None;
+  }
+
+  // Throw
+  if let Some(throw) = statement.get_throw() {
+    let object = evaluate_expression(&throw.get_value(), environment);
+
+    // Check if the value object is an error.
+    if object.get_error().is_some() {
+      return Some(object);
+    }
+
+    return Some(Error::from_object(&object, throw.get_token()));
+  }
+
+  // Try
+  if let Some(try_s) = statement.get_try() {
+    let mut object = evaluate_statement(&try_s.get_body(), environment);
+
+    // Catch the body error.
+    if let Some(error) = object.clone().and_then(|object| object.get_error()) {
+      if let (Some(name), Some(catch)) = (try_s.get_catch_name(), try_s.get_catch()) {
+        // Keep the object with the same name, to restore it after the catch.
+        let previous = environment.store.get_object(&name.value);
+
+        environment.store.set_object(name.value.clone(), error.get_object(environment.running_file.clone(), environment.frames.clone()));
+
+        object = evaluate_statement(&catch, environment);
+
+        match previous {
+          Some(previous) => environment.store.set_object(name.value, previous),
+          None => environment.store.remove_object(&name.value),
+        }
+      }
+    }
+
+    // The finally always runs, and its error, return, break or continue replaces the previous one.
+    if let Some(finally) = try_s.get_finally() {
+      if let Some(finally_object) = evaluate_statement(&finally, environment) {
+        if finally_object.get_error().is_some() ||
+          finally_object.get_return().is_some() ||
+          finally_object.get_break().is_some() ||
+          finally_object.get_continue().is_some() {
+          return Some(finally_object);
+        }
+      }
+    }
+
+    return object;
+  }
+
   // Variable
   if let Some(variable) = statement.get_variable() {
     // Get the variable value.
@@ -202,10 +395,45 @@ pub fn evaluate_statement(
         return Some(object);
       }
 
+      // Check if the variable is a const.
+      if variable.get_token().token.expect_keyword(&Keywords::CONST) {
+        environment.store.set_const(variable.get_name().value, variable.get_name());
+      }
+
+      let object = environment.store.with_optionals(object, &variable.get_type());
+
       environment.store.set_object(variable.get_name().value, object);
     }
   }
 
+  // While
+  if let Some(while_s) = statement.get_while() {
+    loop {
+      let condition_obj = evaluate_expression(&while_s.get_condition(), environment);
+
+      if condition_obj.get_error().is_some() {
+        return Some(condition_obj);
+      }
+
+      if !Boolean::is_truthy(condition_obj) {
+        break;
+      }
+
+      if let Some(obj) = evaluate_statement(&while_s.get_body(), environment) {
+        // Check if the body object is an error or a return.
+        if obj.get_error().is_some() || obj.get_return().is_some() {
+          return Some(obj);
+        }
+
+        if obj.get_break().is_some() {
+          break;
+        }
+      }
+    }
+
+    return None;
+  }
+
   // Default
   None
 }
@@ -115,6 +115,7 @@ pub fn evaluate(
 
   if import.get_modules().len() == 0 {
     for item in exports_items {
+      environment.store.set_const(item.key.clone(), import.get_token());
       environment.store.set_object(item.key, item.value);
     }
   } else {
@@ -123,6 +124,7 @@ pub fn evaluate(
       // Check if the module is an identifier.
       if let Some(identifier) = module.get_identifier() {
         if let Some(env_obj) = import_environment.store.get_object(&identifier.get_value()) {
+          environment.store.set_const(identifier.get_value(), identifier.get_token());
           environment.store.set_object(identifier.get_value(), env_obj);
           continue;
         }
@@ -141,6 +143,7 @@ pub fn evaluate(
             // Get the object from the environment.
             if let Some(env_obj) = import_environment.store.get_object(&left_identifier.get_value()) {
               if let Some(right_identifier) = infix.get_right().unwrap().get_identifier() {
+                environment.store.set_const(right_identifier.get_value(), right_identifier.get_token());
                 environment.store.set_object(right_identifier.get_value(), env_obj);
                 continue;
               }
@@ -148,6 +151,7 @@ pub fn evaluate(
             // Check if the left identifier is an `*`.
             else if left_identifier.get_value() == "*" {
               if let Some(right_identifier) = infix.get_right().unwrap().get_identifier() {
+                environment.store.set_const(right_identifier.get_value(), right_identifier.get_token());
                 environment.store.set_object(right_identifier.get_value(), HashMap::new(exports_items.clone()));
                 continue;
               }
@@ -2,29 +2,44 @@ mod arguments;
 mod package;
 mod store;
 
+pub use arguments::ErrorFormat;
 pub use package::Package;
 pub use store::Store;
 
 use sflyn_parser::{
+  Codes,
+  Error,
   run as run_parser,
   File,
 };
 
-use std::collections::BTreeMap;
+use std::{
+  cell::RefCell,
+  collections::BTreeMap,
+  rc::Rc,
+};
 
-use super::error::show_error;
+use super::{
+  compiler::Frame,
+  error::show_errors,
+};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Environment {
   pub arguments: arguments::Arguments,
 
   pub current_file: Option<File>,
+  pub running_file: String,                  // File where the running code is declared
+  pub frames: Vec<Frame>,                    // Running function calls
   pub files: Vec<File>,
   pub packages: BTreeMap<String, Package>,   // Package name + Package data
 
   pub stdlibs: BTreeMap<String, File>,       // Lib name + Lib file
 
   pub store: Store,
+
+  pub errors: Rc<RefCell<Vec<Error>>>,       // Shared by the cloned environments
+  pub warnings: Rc<RefCell<Vec<Error>>>,     // Shared by the cloned environments
 }
 
 impl Environment {
@@ -33,15 +48,50 @@ impl Environment {
       arguments: arguments::Arguments::from_console(),
 
       current_file: None,
+      running_file: String::new(),
+      frames: Vec::new(),
       files: Vec::new(),
       packages: BTreeMap::new(),
 
       stdlibs: BTreeMap::new(),
 
       store: Store::new(),
+
+      errors: Rc::new(RefCell::new(Vec::new())),
+      warnings: Rc::new(RefCell::new(Vec::new())),
     }
   }
 
+  /// Add an error to the errors list, so the typechecker can continue.
+  pub fn add_error(&self, error: Error) {
+    self.errors.borrow_mut().push(error);
+  }
+
+  /// Add a warning to the warnings list, it is shown with the errors.
+  pub fn add_warning(&self, warning: Error) {
+    self.warnings.borrow_mut().push(warning);
+  }
+
+  /// Get the warnings sorted by line and position.
+  pub fn get_warnings(&self) -> Vec<Error> {
+    let mut warnings: Vec<Error> = self.warnings.borrow().clone();
+
+    warnings.sort_by_key(|warning| (warning.line, warning.start_position));
+    warnings.dedup();
+
+    warnings
+  }
+
+  /// Get the errors sorted by line and position.
+  pub fn get_errors(&self) -> Vec<Error> {
+    let mut errors: Vec<Error> = self.errors.borrow().clone();
+
+    errors.sort_by_key(|error| (error.line, error.start_position));
+    errors.dedup();
+
+    errors
+  }
+
   pub fn get_file(&self, file_name: String) -> Option<File> {
     for file in self.files.iter() {
       if file.name == file_name {
@@ -64,11 +114,11 @@ impl Environment {
 
         return 0;
       }
-      Err((error, file)) => {
+      Err((errors, file)) => {
         if let Some(file) = file {
-          show_error(file, error);
+          show_errors(file, errors, Codes::SYNTAX, &self.arguments.error_format);
         } else {
-          println!("{}", error.message);
+          show_errors(File::new(String::new(), String::new()), errors, Codes::FILE, &self.arguments.error_format);
         }
 
         return 1;
@@ -113,6 +163,11 @@ impl Environment {
       return array_lib;
     }
 
+    let enum_lib = self.load_stdlib(format!("{}std/Enum.sf", sflyn_path), "Enum");
+    if enum_lib != 0 {
+      return enum_lib;
+    }
+
     self.load_stdlib(format!("{}std/range.sf", sflyn_path), "range")
   }
 }
@@ -1,9 +1,27 @@
+#[derive(Debug, Clone, PartialEq)]
+pub enum ErrorFormat {
+  HUMAN,
+  JSON,
+}
+
+impl ErrorFormat {
+  pub fn from_value(value: &str) -> Option<ErrorFormat> {
+    match value {
+      "human" => Some(ErrorFormat::HUMAN),
+      "json" => Some(ErrorFormat::JSON),
+      _ => None,
+    }
+  }
+}
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Arguments {
   pub file: String,
 
   pub flag_version: bool,
+  pub flag_tree_walker: bool,
+  pub flag_vm: bool,
+  pub error_format: ErrorFormat,
 }
 
 impl Arguments {
@@ -12,6 +30,9 @@ impl Arguments {
       file: String::new(),
 
       flag_version: false,
+      flag_tree_walker: false,
+      flag_vm: false,
+      error_format: ErrorFormat::HUMAN,
     }
   }
 
@@ -21,14 +42,38 @@ impl Arguments {
     if arguments.len() == 1 {
       Arguments::new()
     } else {
+      // The file is the first argument that is not a flag.
+      let file = match arguments.iter().skip(1).find(|argument| !argument.starts_with("--")) {
+        Some(file) => file.clone(),
+        None => String::new(),
+      };
+
+      let mut error_format = ErrorFormat::HUMAN;
+
+      for argument in arguments.iter() {
+        if let Some(value) = argument.strip_prefix("--error-format=") {
+          match ErrorFormat::from_value(value) {
+            Some(format) => {
+              error_format = format;
+            },
+            None => {
+              eprintln!("`{}` is not a valid error format, expected `human` or `json`.", value);
+            },
+          }
+        }
+      }
+
       Arguments {
-        file: if std::path::Path::new(&arguments[1].clone()).exists() {
-          arguments[1].clone()
+        file: if std::path::Path::new(&file).exists() {
+          file
         } else {
           String::new()
         },
 
         flag_version: arguments.contains(&String::from("--version")),
+        flag_tree_walker: arguments.contains(&String::from("--tree-walker")),
+        flag_vm: arguments.contains(&String::from("--vm")),
+        error_format,
       }
     }
   }
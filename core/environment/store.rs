@@ -1,81 +1,288 @@
 use crate::{
-  compiler::Objects,
+  compiler::{
+    Instance,
+    Objects,
+  },
   typechecker::TTypes,
 };
 
-use std::collections::HashMap;
+use sflyn_parser::tokens::Token;
 
-#[derive(Debug, Clone, PartialEq)]
-pub struct Store {
-  consts: Vec<String>,
+use std::{
+  cell::RefCell,
+  collections::HashMap,
+  fmt,
+  rc::Rc,
+};
+
+struct Scope {
+  consts: HashMap<String, Token>,
+  imports: Vec<String>,
+
+  // The readonly fields of a class scope, the methods read them by their names.
+  readonly: HashMap<String, Token>,
 
   objects: HashMap<String, Box<Objects>>,
   types: HashMap<String, TTypes>,
 
-  outer: Option<Box<Store>>,
+  // The optional members of the interfaces, they are `null` in the hashmaps without them.
+  optionals: HashMap<String, Vec<String>>,
+
+  // The instance of a method scope, its fields are variables of the scope.
+  instance: Option<Instance>,
+
+  outer: Option<Store>,
+}
+
+/// A lexical scope, shared by the clones, so the closures see the changes of the scope
+/// where they are declared.
+#[derive(Clone)]
+pub struct Store {
+  scope: Rc<RefCell<Scope>>,
+}
+
+impl fmt::Debug for Store {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    // The objects are not shown, because a function is in the scope that it captures.
+    let mut keys: Vec<String> = self.scope.borrow().objects.keys().cloned().collect();
+
+    keys.sort();
+
+    f.debug_struct("Store")
+      .field("objects", &keys)
+      .field("outer", &self.scope.borrow().outer)
+      .finish()
+  }
+}
+
+impl PartialEq for Store {
+  fn eq(&self, other: &Self) -> bool {
+    Rc::ptr_eq(&self.scope, &other.scope)
+  }
 }
 
 impl Store {
   pub fn new() -> Self {
     Self {
-      consts: Vec::new(),
+      scope: Rc::new(RefCell::new(Scope {
+        consts: HashMap::new(),
+        imports: Vec::new(),
 
-      objects: HashMap::new(),
-      types: HashMap::new(),
+        readonly: HashMap::new(),
 
-      outer: None,
+        objects: HashMap::new(),
+        types: HashMap::new(),
+
+        optionals: HashMap::new(),
+
+        instance: None,
+
+        outer: None,
+      })),
     }
   }
 
   pub fn from_store(outer: Store) -> Self {
-    let mut store: Self = Self::new();
+    let store: Self = Self::new();
+
+    store.scope.borrow_mut().outer = Some(outer);
+
+    store
+  }
+
+  /// Create the scope of a method, where the instance fields are read and changed by their names.
+  pub fn from_instance(outer: Store, instance: Instance) -> Self {
+    let store: Self = Self::from_store(outer);
 
-    store.outer = Some(Box::new(outer));
+    store.scope.borrow_mut().instance = Some(instance);
 
     store
   }
 
+  /// Check if the name is a field of the scope instance.
+  fn has_field(&self, name: &String) -> bool {
+    match &self.scope.borrow().instance {
+      Some(instance) => instance.get_field(name).is_some(),
+      None => false,
+    }
+  }
+
   pub fn get_consts(&self) -> Vec<String> {
-    self.consts.clone()
+    self.scope.borrow().consts.keys().cloned().collect()
+  }
+
+  /// Get the declaration token of the const, from the scope where the name is declared.
+  pub fn get_const(&self, name: &String) -> Option<Token> {
+    let scope = self.scope.borrow();
+
+    if let Some(token) = scope.consts.get(name) {
+      return Some(token.clone());
+    }
+
+    // The name is declared in this scope, but not as a const.
+    if scope.objects.contains_key(name) || scope.types.contains_key(name) || self.has_field(name) {
+      return None;
+    }
+
+    match &scope.outer {
+      Some(outer) => outer.get_const(name),
+      None => None,
+    }
+  }
+
+  pub fn has_const(&self, name: &String) -> bool {
+    self.get_const(name).is_some()
+  }
+
+  pub fn set_const(&mut self, name: String, token: Token) {
+    self.scope.borrow_mut().consts.insert(name, token);
+  }
+
+  /// Check if the const is an imported binding.
+  pub fn is_import(&self, name: &String) -> bool {
+    let scope = self.scope.borrow();
+
+    if scope.imports.contains(name) {
+      return true;
+    }
+
+    match &scope.outer {
+      Some(outer) => outer.is_import(name),
+      None => false,
+    }
   }
 
-  pub fn has_const(&mut self, name: &String) -> bool {
-    self.consts.contains(name)
+  /// Set an imported binding, it is a const.
+  pub fn set_import(&mut self, name: String, token: Token) {
+    self.scope.borrow_mut().imports.push(name.clone());
+    self.set_const(name, token);
   }
 
-  pub fn set_const(&mut self, name: String) {
-    self.consts.push(name)
+  /// Get the declaration token of the readonly field, from the scope where the name is declared.
+  pub fn get_readonly(&self, name: &String) -> Option<Token> {
+    let scope = self.scope.borrow();
+
+    if let Some(token) = scope.readonly.get(name) {
+      return Some(token.clone());
+    }
+
+    // The name is declared in this scope, but not as a readonly field.
+    if scope.objects.contains_key(name) || scope.types.contains_key(name) || self.has_field(name) {
+      return None;
+    }
+
+    match &scope.outer {
+      Some(outer) => outer.get_readonly(name),
+      None => None,
+    }
+  }
+
+  pub fn set_readonly(&mut self, name: String, token: Token) {
+    self.scope.borrow_mut().readonly.insert(name, token);
   }
 
   pub fn get_object(&self, key: &String) -> Option<Box<Objects>> {
-    match self.objects.get(key) {
-      Some(object) => Some(object.clone()),
-      None => match self.get_outer() {
-        Some(outer) => outer.get_object(key),
-        None => None,
-      },
+    let scope = self.scope.borrow();
+
+    if let Some(object) = scope.objects.get(key) {
+      return Some(object.clone());
+    }
+
+    // Check if the name is a field of the instance.
+    if let Some(field) = scope.instance.as_ref().and_then(|instance| instance.get_field(key)) {
+      return Some(field);
+    }
+
+    match &scope.outer {
+      Some(outer) => outer.get_object(key),
+      None => None,
     }
   }
 
   pub fn set_object(&mut self, key: String, value: Box<Objects>) {
-    self.objects.insert(key, value);
+    self.scope.borrow_mut().objects.insert(key, value);
+  }
+
+  pub fn remove_object(&mut self, key: &String) {
+    self.scope.borrow_mut().objects.remove(key);
+  }
+
+  /// Get the scope where the object is declared.
+  fn get_object_store(&self, key: &String) -> Option<Store> {
+    let scope = self.scope.borrow();
+
+    if scope.objects.contains_key(key) || self.has_field(key) {
+      return Some(self.clone());
+    }
+
+    match &scope.outer {
+      Some(outer) => outer.get_object_store(key),
+      None => None,
+    }
+  }
+
+  /// Set the object in the scope where it is declared, or in this one when it is not declared.
+  pub fn update_object(&mut self, key: String, value: Box<Objects>) {
+    match self.get_object_store(&key) {
+      Some(mut store) => {
+        let instance = store.scope.borrow().instance.clone();
+
+        // Check if the name is a field of the instance, and not a variable of the scope.
+        match instance {
+          Some(instance) if !store.scope.borrow().objects.contains_key(&key) => instance.set_field(key, value),
+          _ => store.set_object(key, value),
+        }
+      },
+      None => self.set_object(key, value),
+    }
   }
 
   pub fn get_type(&self, key: &String) -> Option<TTypes> {
-    match self.types.get(key) {
+    let scope = self.scope.borrow();
+
+    match scope.types.get(key) {
       Some(data_type) => Some(data_type.clone()),
-      None => match self.get_outer() {
+      None => match &scope.outer {
         Some(outer) => outer.get_type(key),
         None => None,
       },
     }
   }
 
+  /// Check if the type is declared in this scope, and not in an outer one.
+  pub fn has_own_type(&self, key: &String) -> bool {
+    self.scope.borrow().types.contains_key(key)
+  }
+
   pub fn set_type(&mut self, key: String, value: TTypes) {
-    self.types.insert(key, value);
+    self.scope.borrow_mut().types.insert(key, value);
+  }
+
+  pub fn get_optionals(&self, key: &String) -> Option<Vec<String>> {
+    let scope = self.scope.borrow();
+
+    match scope.optionals.get(key) {
+      Some(optionals) => Some(optionals.clone()),
+      None => match &scope.outer {
+        Some(outer) => outer.get_optionals(key),
+        None => None,
+      },
+    }
+  }
+
+  pub fn set_optionals(&mut self, key: String, value: Vec<String>) {
+    self.scope.borrow_mut().optionals.insert(key, value);
+  }
+
+  /// Add the missing optional members to a hashmap of an interface data type.
+  pub fn with_optionals(&self, object: Box<Objects>, data_type: &Token) -> Box<Objects> {
+    match (object.get_hashmap(), self.get_optionals(&data_type.value)) {
+      (Some(hashmap), Some(optionals)) => hashmap.with_optionals(&optionals),
+      _ => object,
+    }
   }
 
-  pub fn get_outer(&self) -> Option<Box<Store>> {
-    self.outer.clone()
+  pub fn get_outer(&self) -> Option<Store> {
+    self.scope.borrow().outer.clone()
   }
 }
@@ -1,27 +1,322 @@
 use sflyn_parser::{
+  Codes,
+  Diagnostic,
   Error,
   File,
+  Label,
+  Severity,
 };
 
-use super::utils::repeat_character;
+use super::{
+  ErrorFormat,
+  utils::repeat_character,
+};
+
+use std::io::IsTerminal;
+
+/// Check if the diagnostics can use ANSI colours.
+fn use_colors() -> bool {
+  std::env::var("NO_COLOR").is_err() && std::io::stderr().is_terminal()
+}
+
+/// Paint the text with an ANSI colour when the colours are enabled.
+fn paint(text: String, color: &str, colors: bool) -> String {
+  if colors {
+    format!("\x1b[{}m{}\x1b[0m", color, text)
+  } else {
+    text
+  }
+}
+
+fn get_severity_color(severity: &Severity) -> &str {
+  match severity {
+    Severity::ERROR => "1;31",
+    Severity::WARNING => "1;33",
+    Severity::NOTE => "1;36",
+  }
+}
+
+/// Render a span of the file with the marker below it.
+fn render_span(
+  file: &File,
+  label: &Label,
+  marker: &str,
+  gutter: usize,
+  color: &str,
+  colors: bool,
+) -> String {
+  let lines = file.get_lines();
+
+  if label.line < 1 || label.line > lines.len() {
+    return String::new();
+  }
+
+  let mut markers = repeat_character(label.end_position.saturating_sub(label.start_position).max(1), marker);
+
+  if !label.message.is_empty() {
+    markers = format!("{} {}", markers, label.message);
+  }
+
+  format!(
+    "{}{} {} {}\n{} {}{}{}\n",
+    label.line,
+    repeat_character(gutter - label.line.to_string().len(), " "),
+    paint(String::from("|"), "1;34", colors),
+    lines[label.line - 1],
+    repeat_character(gutter, " "),
+    paint(String::from("|"), "1;34", colors),
+    repeat_character(label.start_position, " "),
+    paint(markers, color, colors),
+  )
+}
+
+/// Get the file with the name, reading it when it is not the current file.
+fn load_file(file: &File, file_name: &str) -> File {
+  if file.name == file_name {
+    return file.clone();
+  }
+
+  File::new(file_name.to_string(), std::fs::read_to_string(file_name).unwrap_or_default())
+}
+
+/// Render a diagnostic, with its spans, labels, help text and trace.
+pub fn render_diagnostic(
+  file: &File,
+  diagnostic: &Diagnostic,
+  colors: bool,
+) -> String {
+  let file = &match diagnostic.file.clone() {
+    Some(file_name) => load_file(file, &file_name),
+    None => file.clone(),
+  };
+
+  let color = get_severity_color(&diagnostic.severity);
+
+  let mut message = format!(
+    "{}: {}\n",
+    paint(
+      format!("{}[{}]", diagnostic.severity.string(), diagnostic.code.get_code()),
+      color,
+      colors,
+    ),
+    diagnostic.message,
+  );
+
+  if diagnostic.line < 1 {
+    return message;
+  }
+
+  // Width of the line numbers column.
+  let gutter = diagnostic.labels.iter()
+    .map(|label| label.line)
+    .chain(diagnostic.trace.iter().map(|frame| frame.line))
+    .chain(std::iter::once(diagnostic.line))
+    .max()
+    .unwrap_or(0)
+    .to_string()
+    .len();
+
+  message.push_str(&format!(
+    "{}{} {}:{}:{}\n",
+    repeat_character(gutter, " "),
+    paint(String::from("-->"), "1;34", colors),
+    file.name,
+    diagnostic.line,
+    diagnostic.start_position,
+  ));
+
+  let primary = Label {
+    message: String::new(),
+    line: diagnostic.line,
+    start_position: diagnostic.start_position,
+    end_position: diagnostic.end_position,
+  };
+
+  message.push_str(&render_span(file, &primary, "^", gutter, color, colors));
+
+  for label in diagnostic.labels.iter() {
+    message.push_str(&render_span(file, label, "-", gutter, "1;34", colors));
+  }
+
+  if let Some(help) = diagnostic.help.clone() {
+    message.push_str(&format!(
+      "{} {} {}\n",
+      repeat_character(gutter, " "),
+      paint(String::from("= help:"), "1", colors),
+      help,
+    ));
+  }
+
+  // Show the function calls like a stack.
+  for frame in diagnostic.trace.iter() {
+    message.push_str(&format!(
+      "{} at `{}` {}:{}:{}\n",
+      repeat_character(gutter, " "),
+      frame.name,
+      frame.file,
+      frame.line,
+      frame.start_position,
+    ));
+
+    let call = Label {
+      message: String::new(),
+      line: frame.line,
+      start_position: frame.start_position,
+      end_position: frame.end_position,
+    };
+
+    message.push_str(&render_span(&load_file(file, &frame.file), &call, "^", gutter, "1;34", colors));
+  }
+
+  message
+}
+
+/// Escape a string to use it as a JSON string.
+fn escape_json(value: &str) -> String {
+  let mut escaped = String::new();
+
+  for character in value.chars() {
+    match character {
+      '"' => escaped.push_str("\\\""),
+      '\\' => escaped.push_str("\\\\"),
+      '\n' => escaped.push_str("\\n"),
+      '\r' => escaped.push_str("\\r"),
+      '\t' => escaped.push_str("\\t"),
+      character if (character as u32) < 0x20 => {
+        escaped.push_str(&format!("\\u{:04x}", character as u32));
+      },
+      character => escaped.push(character),
+    }
+  }
+
+  format!("\"{}\"", escaped)
+}
+
+/// Render a diagnostic as a JSON object in one line.
+/// The columns start at 1 and the end column is exclusive.
+pub fn render_json(
+  file: &File,
+  diagnostic: &Diagnostic,
+) -> String {
+  let labels = diagnostic.labels.iter()
+    .map(|label| format!(
+      "{{\"message\":{},\"line\":{},\"column_start\":{},\"column_end\":{}}}",
+      escape_json(&label.message),
+      label.line,
+      label.start_position,
+      label.end_position,
+    ))
+    .collect::<Vec<String>>();
+
+  let trace = diagnostic.trace.iter()
+    .map(|frame| format!(
+      "{{\"function\":{},\"file\":{},\"line\":{},\"column_start\":{},\"column_end\":{}}}",
+      escape_json(&frame.name),
+      escape_json(&frame.file),
+      frame.line,
+      frame.start_position,
+      frame.end_position,
+    ))
+    .collect::<Vec<String>>();
+
+  format!(
+    "{{\"file\":{},\"severity\":{},\"code\":{},\"message\":{},\"line\":{},\"column_start\":{},\"column_end\":{},\"labels\":[{}],\"help\":{},\"trace\":[{}]}}",
+    escape_json(&diagnostic.file.clone().unwrap_or(file.name.clone())),
+    escape_json(&diagnostic.severity.string()),
+    escape_json(&diagnostic.code.get_code()),
+    escape_json(&diagnostic.message),
+    diagnostic.line,
+    diagnostic.start_position,
+    diagnostic.end_position,
+    labels.join(","),
+    match diagnostic.help.clone() {
+      Some(help) => escape_json(&help),
+      None => String::from("null"),
+    },
+    trace.join(","),
+  )
+}
+
+/// Print a diagnostic to the standard error.
+pub fn show_diagnostic(
+  file: &File,
+  diagnostic: &Diagnostic,
+  format: &ErrorFormat,
+) {
+  match format {
+    ErrorFormat::HUMAN => eprint!("{}", render_diagnostic(file, diagnostic, use_colors())),
+    ErrorFormat::JSON => eprintln!("{}", render_json(file, diagnostic)),
+  }
+}
 
 pub fn show_error(
   file: File,
   error: Error,
+  default_code: Codes,
+  format: &ErrorFormat,
 ) {
-  if error.line > 0 {
-    let line = file.get_lines()[error.line - 1].to_string();
-
-    println!(
-      "{} | {}\n{} | {}{} {}",
-      error.line,
-      line,
-      repeat_character(error.line.to_string().len(), " "),
-      repeat_character(error.start_position - 1, " "),
-      repeat_character(error.end_position - error.start_position, "^"),
-      error.message,
-    );
-  } else {
-    println!("{}", error.message);
+  show_diagnostic(&file, &Diagnostic::from_error(Severity::ERROR, error, default_code), format);
+}
+
+/// Show the errors and the number of errors found.
+pub fn show_errors(
+  file: File,
+  errors: Vec<Error>,
+  default_code: Codes,
+  format: &ErrorFormat,
+) {
+  show_diagnostics(file, errors, Vec::new(), default_code, format);
+}
+
+/// Show the errors and the warnings sorted by line and position, and the number of them found.
+pub fn show_diagnostics(
+  file: File,
+  errors: Vec<Error>,
+  warnings: Vec<Error>,
+  default_code: Codes,
+  format: &ErrorFormat,
+) {
+  let mut diagnostics: Vec<Diagnostic> = Vec::new();
+
+  for error in errors.iter() {
+    diagnostics.push(Diagnostic::from_error(Severity::ERROR, error.clone(), default_code.clone()));
+  }
+
+  for warning in warnings.iter() {
+    diagnostics.push(Diagnostic::from_error(Severity::WARNING, warning.clone(), default_code.clone()));
+  }
+
+  diagnostics.sort_by_key(|diagnostic| (diagnostic.line, diagnostic.start_position));
+
+  for diagnostic in diagnostics.iter() {
+    show_diagnostic(&file, diagnostic, format);
+  }
+
+  // The JSON output only contains the diagnostics.
+  if *format == ErrorFormat::JSON {
+    return;
+  }
+
+  let mut counts: Vec<String> = Vec::new();
+
+  if !errors.is_empty() {
+    counts.push(format!("{} {}", errors.len(), if errors.len() == 1 { "error" } else { "errors" }));
+  }
+
+  if !warnings.is_empty() {
+    counts.push(format!("{} {}", warnings.len(), if warnings.len() == 1 { "warning" } else { "warnings" }));
+  }
+
+  if !counts.is_empty() {
+    eprintln!("found {}.", counts.join(" and "));
   }
 }
+
+pub fn show_warning(
+  file: File,
+  warning: Error,
+  default_code: Codes,
+  format: &ErrorFormat,
+) {
+  show_diagnostic(&file, &Diagnostic::from_error(Severity::WARNING, warning, default_code), format);
+}
@@ -4,5 +4,6 @@ pub mod error;
 pub mod program;
 pub mod typechecker;
 pub mod utils;
+pub mod vm;
 
 pub use environment::*;
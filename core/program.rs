@@ -1,9 +1,15 @@
 use super::{
   compiler,
   Environment,
-  error::show_error,
+  error::show_errors,
   typechecker,
   utils::get_sflyn_path,
+  vm,
+};
+
+use sflyn_parser::{
+  Codes,
+  File,
 };
 
 pub fn run_file(
@@ -16,6 +22,7 @@ pub fn run_file(
   match sflyn_parser::run(file_name) {
     Ok(file) => {
       environment.current_file = Some(file.clone());
+      environment.running_file = file.name.clone();
 
       let mut file = file.clone();
 
@@ -27,18 +34,27 @@ pub fn run_file(
         }
 
         if with_compiler {
-          compiler::run(file.clone(), environment, with_stdlib);
+          // The tree-walking evaluator is kept while the virtual machine is completed.
+          let result = if environment.arguments.flag_tree_walker {
+            compiler::run(file.clone(), environment, with_stdlib)
+          } else {
+            vm::run(file.clone(), environment, with_stdlib)
+          };
+
+          if result != 0 {
+            return 1;
+          }
         }
       }
 
       environment.files.push(file.clone());
     },
 
-    Err((error, file)) => {
+    Err((errors, file)) => {
       if let Some(file) = file {
-        show_error(file, error);
+        show_errors(file, errors, Codes::SYNTAX, &environment.arguments.error_format);
       } else {
-        println!("{}", error.message);
+        show_errors(File::new(String::new(), String::new()), errors, Codes::FILE, &environment.arguments.error_format);
       }
 
       return 1;
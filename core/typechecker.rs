@@ -8,42 +8,76 @@ pub use statements::*;
 pub use types::*;
 pub use utils::*;
 
-use sflyn_parser::File;
+use sflyn_parser::{
+  Codes,
+  Error,
+  File,
+};
 
 use super::{
   Environment,
-  error::show_error,
+  error::{
+    show_diagnostics,
+    show_errors,
+  },
+};
+
+use std::{
+  cell::RefCell,
+  rc::Rc,
 };
 
 pub fn run(
   file: &mut File,
   environment: &mut Environment,
   with_stdlib: bool,
-) -> Result<(), ()> {
+) -> Result<(), Vec<Error>> {
+  // Use new errors and warnings lists for the file.
+  environment.errors = Rc::new(RefCell::new(Vec::new()));
+  environment.warnings = Rc::new(RefCell::new(Vec::new()));
+
   // Add stdlib.
   if environment.stdlibs.len() > 0 && with_stdlib {
     for (name, file) in environment.stdlibs.clone().iter() {
       if file.statements.len() == 0 {
         println!("`{}` library is an empty file.", name);
-        return Err(());
+        return Err(Vec::new());
       }
 
       for statement in file.statements.iter() {
         if let Err(error) = check_statement(statement, environment) {
-          show_error(file.clone(), error);
-          return Err(());
+          environment.add_error(error);
+          recover_statement(statement, environment);
         }
       }
+
+      let errors = environment.get_errors();
+
+      if !errors.is_empty() {
+        show_errors(file.clone(), errors.clone(), Codes::TYPE, &environment.arguments.error_format);
+        return Err(errors);
+      }
     }
   }
 
-  // Parse file statements.
+  // Check file statements.
   for statement in file.statements.iter() {
     if let Err(error) = check_statement(statement, environment) {
-      show_error(file.clone(), error);
-      return Err(());
+      environment.add_error(error);
+      recover_statement(statement, environment);
     }
   }
 
+  let errors = environment.get_errors();
+  let warnings = environment.get_warnings();
+
+  if !errors.is_empty() || !warnings.is_empty() {
+    show_diagnostics(file.clone(), errors.clone(), warnings, Codes::TYPE, &environment.arguments.error_format);
+  }
+
+  if !errors.is_empty() {
+    return Err(errors);
+  }
+
   Ok(())
 }
@@ -7,6 +7,7 @@ mod hashmap;
 mod infix;
 mod prefix;
 mod suffix;
+mod template;
 
 pub use argument::function_arguments_to_string;
 
@@ -16,6 +17,7 @@ use crate::{
 };
 
 use sflyn_parser::{
+  Codes,
   Error,
   Expression,
   Expressions,
@@ -68,7 +70,7 @@ pub fn check_expression(
       None => Err(Error::from_token(
         format!("`{} identifier not found.`", identifier.get_value()),
         identifier.get_token(),
-      )),
+      ).with_code(Codes::IDENTIFIERNOTFOUND)),
     }
   }
 
@@ -102,6 +104,11 @@ pub fn check_expression(
     return suffix::check(&suffix_exp, environment);
   }
 
+  // Template
+  if let Some(template_exp) = expression.get_template() {
+    return template::check(&template_exp, environment);
+  }
+
   // Default
   Err(Error::from_token(
     String::from("unknown expression."),
@@ -3,7 +3,7 @@ use crate::{
   Store,
   typechecker::{
     check_statement,
-    equal_types,
+    equal_ttypes,
     function_arguments_to_string,
     TTypes,
   },
@@ -11,6 +11,7 @@ use crate::{
 
 use sflyn_parser::{
   AnonymousFunction,
+  Codes,
   Error,
   Expression,
   tokens::Token,
@@ -43,11 +44,11 @@ pub fn check(
   match check_statement(&anonymous_function.get_body(), &mut function_environment) {
     Ok(token) => {
       if let Some(ttoken) = data_type.token.get_type() {
-        if !equal_types(ttoken, token.get_type()) && token.get_value() != "any" {
+        if !equal_ttypes(ttoken, &token) && token.get_value() != "any" {
           return Err(Error::from_token(
             format!("`{}` not satisfied the `{}` data type.", token.get_token().value, data_type.value),
             token.get_token(),
-          ));
+          ).with_code(Codes::NOTSATISFIED));
         }
       } else {
         return Err(Error::from_token(
@@ -61,6 +62,12 @@ pub fn check(
     },
   }
 
+  let mut generics: Vec<String> = Vec::new();
+
+  for generic in anonymous_function.get_generics().iter() {
+    generics.push(generic.value.clone());
+  }
+
   let value = format!("({}) => {}", arguments.join(", "), data_type.value);
   let token = Token::from_value(value.as_str(), 0, 0);
 
@@ -75,6 +82,7 @@ pub fn check(
     token.token.get_type().unwrap(),
     token.value,
     anonymous_function.get_token(),
+    generics,
     anonymous_function.get_arguments(),
   ));
 }
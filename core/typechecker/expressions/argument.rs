@@ -1,18 +1,19 @@
 use crate::{
   Environment,
-  typechecker::TTypes,
+  typechecker::{
+    get_identifier_ttype,
+    get_ttypes_from_token,
+    TTypes,
+  },
 };
 
 use sflyn_parser::{
   Argument,
+  Codes,
   Error,
   Expression,
   Expressions,
-  tokens::{
-    Token,
-    Tokens,
-    Types,
-  },
+  tokens::Token,
 };
 
 pub fn function_arguments_to_string(
@@ -32,7 +33,7 @@ pub fn function_arguments_to_string(
       return Err(Error::from_token(
         format!("`{}` is already in use.", argument_name.value.clone()),
         argument_name,
-      ));
+      ).with_code(Codes::ALREADYINUSE));
     }
 
     // Add the argument name to the arguments names list.
@@ -43,9 +44,18 @@ pub fn function_arguments_to_string(
     // Check if the argument data type is an identifier.
     if argument.get_type().token.is_identifier() {
       // Get the interface type from the environment store.
-      match environment.store.get_type(&argument.get_type().value) {
+      match get_identifier_ttype(&argument.get_type().value, environment) {
         Some(data_type) => {
-          ttype = data_type;
+          // Check if the data type is a class.
+          if data_type.is_class() {
+            ttype = data_type.to_instance(argument.get_token());
+          }
+          // Check if the data type is an enum.
+          else if data_type.is_enum() {
+            ttype = data_type.to_enum_variant(argument.get_token());
+          } else {
+            ttype = data_type;
+          }
         }
         None => {
           return Err(Error::from_token(
@@ -68,34 +78,25 @@ pub fn function_arguments_to_string(
         argument.get_type(),
       ));
     } else {
-      let data_type: Types = argument.get_type().token.get_type().unwrap();
-
-      if let Some(function) = data_type.get_function() {
-        let mut arguments: Vec<Box<Expressions>> = Vec::new();
-
-        for (key, value) in function.get_arguments().iter() {
-          arguments.push(Argument::new_box_full(
-            Token::new(Box::new(Tokens::IDENTIFIER), key.clone(), 0, 0),
-            value.clone(),
-            None,
-          ));
-        }
-
-        ttype = TTypes::new_function(
-          data_type,
-          argument.get_type().value,
-          argument.get_token(),
-          arguments,
-        );
-      } else {
-        ttype = TTypes::new_type(
-          data_type,
-          argument.get_type().value,
-          argument.get_token()
-        );
+      match get_ttypes_from_token(argument.get_type(), argument.get_token()) {
+        Some(data_type) => {
+          ttype = data_type;
+        },
+        None => {
+          ttype = TTypes::new_type(
+            argument.get_type().token.get_type().unwrap(),
+            argument.get_type().value,
+            argument.get_token()
+          );
+        },
       }
     }
 
+    // Check if the argument is a const.
+    if argument.is_const() {
+      function_environment.store.set_const(argument_name.value.clone(), argument_name.clone());
+    }
+
     // Add the argument to the closed environment.
     function_environment.store.set_type(argument_name.value, ttype);
 
@@ -3,6 +3,8 @@ use crate::{
   typechecker::{
     check_expression,
     equal_types,
+    get_identifier_ttype,
+    get_identifier_value_ttype,
     TTypes,
   },
 };
@@ -10,6 +12,7 @@ use crate::{
 use sflyn_parser::{
   Array,
   ArrayIndex,
+  Codes,
   Error,
   Expression,
   tokens::{
@@ -41,7 +44,7 @@ pub fn check(
             return Err(Error::from_token(
               format!("`{}` not satisfied the `{}` data type.", token.get_value(), data_type.unwrap().get_value()),
               token.get_token(),
-            ));
+            ).with_code(Codes::NOTSATISFIED));
           }
 
           continue;
@@ -101,12 +104,17 @@ pub fn check_index(
       return Err(Error::from_token(
         format!("`{}` identifier not found.", array_index.get_token().value),
         array_index.get_token(),
-      ));
+      ).with_code(Codes::IDENTIFIERNOTFOUND));
     }
   }
 
   let array_type = array_type.unwrap();
 
+  // Do not report more errors for a failed binding.
+  if array_type.is_error() {
+    return Ok(array_type);
+  }
+
   if array_type.get_type() == Types::STRING {
     return Ok(TTypes::new_type(
       array_type.get_type(),
@@ -122,6 +130,18 @@ pub fn check_index(
 
   let array = array_type.get_type().get_array().unwrap().get_type();
 
+  // Check if the elements are instances of a class or variants of an enum.
+  if array.token.get_type().is_none() {
+    return match get_identifier_ttype(&array.value, environment)
+      .and_then(|ttype| get_identifier_value_ttype(&ttype, array_index.get_token())) {
+      Some(ttype) => Ok(ttype),
+      None => Err(Error::from_token(
+        format!("`{}` identifier not found.", array.value),
+        array_index.get_token(),
+      ).with_code(Codes::IDENTIFIERNOTFOUND)),
+    };
+  }
+
   Ok(TTypes::new_type(
     array.token.get_type().unwrap(),
     array.value,
@@ -2,8 +2,12 @@ use crate::{
   Environment,
   typechecker::{
     check_expression,
+    equal_ttypes,
     equal_types,
+    get_identifier_ttype,
+    get_identifier_value_ttype,
     get_ttypes_from_token,
+    infer_generics,
     TTypes,
   },
 };
@@ -11,11 +15,14 @@ use crate::{
 use sflyn_parser::{
   Argument,
   Call,
+  Codes,
   Error,
   Expression,
   tokens::Token,
 };
 
+use std::collections::HashMap;
+
 pub fn check(
   call: &Call,
   environment: &mut Environment,
@@ -27,12 +34,17 @@ pub fn check(
     return Err(Error::from_token(
       format!("`{}` identifier not found.", call.get_token().value),
       call.get_token(),
-    ));
+    ).with_code(Codes::IDENTIFIERNOTFOUND));
   }
 
   let function_type: TTypes = function_type.unwrap();
 
-  if !function_type.is_function() {
+  // Do not report more errors for a failed binding.
+  if function_type.is_error() {
+    return Ok(function_type);
+  }
+
+  if !function_type.is_function() && !function_type.is_class() {
     return Err(Error::from_token(
       format!("`{}` is not a function.", call.get_token().value),
       call.get_token(),
@@ -62,7 +74,7 @@ pub fn check(
         call.get_arguments().len(),
       ),
       call.get_token(),
-    ));
+    ).with_code(Codes::ARGUMENTS));
   }
 
   if call.get_arguments().len() > max_arguments {
@@ -73,7 +85,7 @@ pub fn check(
         call.get_arguments().len(),
       ),
       call.get_token(),
-    ));
+    ).with_code(Codes::ARGUMENTS));
   }
 
   // Get call arguments types.
@@ -90,15 +102,73 @@ pub fn check(
     }
   }
 
+  let mut function_type: TTypes = function_type;
+
+  // Infer the generic data types from the call arguments.
+  if function_type.is_function() && !function_type.get_names().is_empty() {
+    let mut generics: HashMap<String, String> = HashMap::new();
+
+    for (index, argument) in call_arguments_types.iter().enumerate() {
+      let function_argument: Token = function_type.get_arguments()[index].get_argument().unwrap().get_type();
+
+      if let Some(data_type) = function_argument.token.get_type() {
+        infer_generics(data_type, argument.get_type(), argument.get_value(), &mut generics);
+      }
+    }
+
+    for name in function_type.get_names().iter() {
+      if !generics.contains_key(name) {
+        return Err(Error::from_token(
+          format!("can not infer the `{}` generic data type.", name),
+          call.get_token(),
+        ).with_code(Codes::GENERICNOTINFERRED));
+      }
+    }
+
+    function_type = function_type.replace_generics(&generics);
+  }
+
   // Compare arguments types.
-  let mut index: usize = 0;
+  for (index, argument) in call_arguments_types.iter().enumerate() {
+    // Do not report more errors for a failed binding.
+    if argument.is_error() {
+      continue;
+    }
 
-  for argument in call_arguments_types.iter() {
     let call_token: Token = call.get_arguments()[index].token();
     let function_argument: Token = function_type.get_arguments()[index].get_argument().unwrap().get_type();
 
     if function_argument.token.is_identifier() {
-      if let Some(interface_type) = environment.store.get_type(&function_argument.value) {
+      if let Some(interface_type) = get_identifier_ttype(&function_argument.value, environment) {
+        // Do not report more errors for a failed declaration.
+        if interface_type.is_error() {
+          continue;
+        }
+
+        // Check if the argument is an instance of the class.
+        if interface_type.is_class() {
+          if argument.is_instance() && argument.get_value() == interface_type.get_value() {
+            continue;
+          }
+
+          return Err(Error::from_token(
+            format!("`{}` is not an instance of the `{}` class.", call_token.value, function_argument.value),
+            call_token,
+          ));
+        }
+
+        // Check if the argument is a variant of the enum.
+        if interface_type.is_enum() {
+          if argument.is_enum_variant() && argument.get_value() == interface_type.get_value() {
+            continue;
+          }
+
+          return Err(Error::from_token(
+            format!("`{}` is not a `{}` variant.", call_token.value, function_argument.value),
+            call_token,
+          ));
+        }
+
         if interface_type.is_interface() {
           if equal_types(interface_type.get_type(), argument.get_type()) {
             continue;
@@ -107,7 +177,7 @@ pub fn check(
           return Err(Error::from_token(
             format!("`{}` not satisfied the `{}` interface.", argument.get_value(), function_argument.value),
             call_token,
-          ));
+          ).with_code(Codes::NOTSATISFIED));
         }
 
         return Err(Error::from_token(
@@ -119,23 +189,41 @@ pub fn check(
       return Err(Error::from_token(
         format!("`{}` identifier not found.", function_argument.value),
         call_token,
-      ));
+      ).with_code(Codes::IDENTIFIERNOTFOUND));
     } else if function_argument.token.get_type().is_some() {
-      if equal_types(function_argument.token.get_type().unwrap(), argument.get_type()) {
+      if equal_ttypes(function_argument.token.get_type().unwrap(), argument) {
         continue;
       }
 
       return Err(Error::from_token(
         format!("`{}` not satisfied the `{}` data type.", argument.get_value(), function_argument.value),
         call_token,
-      ));
+      ).with_code(Codes::NOTSATISFIED));
     }
+  }
 
-    index += 1;
+  // Check if is a class constructor.
+  if function_type.is_class() {
+    return Ok(function_type.to_instance(call.get_token()));
   }
 
   if let Some(function) = function_type.get_type().get_function() {
-    if let Some(ttype) = get_ttypes_from_token(function.get_type(), call.get_token()) {
+    let return_type: Token = function.get_type();
+
+    // Check if the function returns a class instance, an enum variant or an interface, the return
+    // data type is read from the function data type value, so it is not an identifier token.
+    if return_type.token.get_type().is_none() {
+      if let Some(ttype) = get_identifier_ttype(&return_type.value, environment) {
+        // Do not report more errors for a failed declaration.
+        if ttype.is_error() {
+          return Ok(ttype);
+        }
+
+        if let Some(ttype) = get_identifier_value_ttype(&ttype, call.get_token()) {
+          return Ok(ttype);
+        }
+      }
+    } else if let Some(ttype) = get_ttypes_from_token(return_type, call.get_token()) {
       return Ok(ttype);
     }
   }
@@ -2,14 +2,20 @@ use crate::{
   Environment,
   Store,
   typechecker::{
+    check_assignable,
     check_expression,
+    equal_ttypes,
     equal_types,
+    get_narrowed_types,
+    get_std_interface,
     get_ttypes_from_token,
+    is_nullable,
     TTypes,
   },
 };
 
 use sflyn_parser::{
+  Codes,
   Error,
   Expression,
   Infix,
@@ -21,6 +27,37 @@ use sflyn_parser::{
   },
 };
 
+use std::collections::HashMap;
+
+/// Check if the member can be accessed from the current environment.
+fn check_member_access(
+  owner_type: &TTypes,
+  member: Token,
+  environment: &mut Environment,
+) -> Result<(), Error> {
+  let access: &str;
+
+  if owner_type.has_modifier(&member.value, Keywords::PRIVATE) {
+    access = "private";
+  } else if owner_type.has_modifier(&member.value, Keywords::PROTECTED) {
+    access = "protected";
+  } else {
+    return Ok(());
+  }
+
+  // Check if the current environment is inside the same class.
+  if let Some(this_type) = environment.store.get_type(&String::from("this")) {
+    if this_type.is_instance() && this_type.get_value() == owner_type.get_value() {
+      return Ok(());
+    }
+  }
+
+  Err(Error::from_token(
+    format!("`{}` is a {} member of `{}`.", member.value, access, owner_type.get_value()),
+    member,
+  ))
+}
+
 pub fn check(
   infix: &Infix,
   environment: &mut Environment,
@@ -48,6 +85,11 @@ pub fn check(
   } else {
     match check_expression(&infix.get_left(), environment) {
       Ok(token) => {
+        // Do not report more errors for a failed binding.
+        if token.is_error() {
+          return Ok(token);
+        }
+
         left_type = Some(token);
       },
       Err(error) => {
@@ -56,23 +98,7 @@ pub fn check(
     }
 
     if infix.is_variable_set() {
-      let mut name = String::new();
-      let mut token = Token::new_empty();
-
-      if let Some(identifier) = infix.get_left().get_identifier() {
-        name = identifier.get_value();
-        token = identifier.get_token();
-      } else if let Some(array_index) = infix.get_left().get_array_index() {
-        name = array_index.get_token().value;
-        token = array_index.get_token();
-      }
-
-      if environment.store.has_const(&name) {
-        return Err(Error::from_token(
-          format!("`{}` is a const.", name),
-          token,
-        ));
-      }
+      check_assignable(&infix.get_left(), environment)?;
     }
   } 
 
@@ -81,46 +107,66 @@ pub fn check(
 
   right_environment.store = Store::from_store(environment.store.clone());
 
+  // Narrow the union types of the left condition for the right expression.
+  if let Some((name, narrowed, rest)) = get_narrowed_types(&infix.get_left(), environment) {
+    if infix.get_token().token.expect_sign(&Signs::AND) {
+      right_environment.store.set_type(name, narrowed);
+    } else if infix.get_token().token.expect_sign(&Signs::OR) {
+      if let Some(rest) = rest {
+        right_environment.store.set_type(name, rest);
+      }
+    }
+  }
+
   // Check if is a method.
   if infix.is_method() && left_type.clone().is_some() {
     let left_type: TTypes = left_type.clone().unwrap();
-    let mut from_std = "";
 
-    // Check if the left type is null.
-    if left_type.get_type() == Types::NULL {
-      from_std = "Null";
-    }
-    // Check if the left type is a string.
-    else if left_type.get_type() == Types::STRING {
-      from_std = "String";
-    }
-    // Check if the left type is a number.
-    else if left_type.get_type() == Types::NUMBER {
-      from_std = "Number";
-    }
-    // Check if the left type is a boolean.
-    else if left_type.get_type() == Types::BOOLEAN {
-      from_std = "Boolean";
-    }
-    // Check if the left type is an array.
-    else if left_type.get_type().get_array().is_some() {
-      from_std = "Array";
+    // Check if the left type can be null.
+    if is_nullable(&left_type) {
+      // Only the variables are narrowed by the guards, so the members are saved in one before.
+      let help = match infix.get_left().get_identifier() {
+        Some(_) => format!("check `{} != null` before using `->`.", infix.get_left().string()),
+        None => format!(
+          "save `{}` in a variable and check that it is not `null` before using `->`.",
+          infix.get_left().string(),
+        ),
+      };
+
+      return Err(Error::from_token(
+        format!("`{}` is possibly null.", infix.get_left().string()),
+        infix.get_left().token(),
+      )
+        .with_code(Codes::POSSIBLYNULL)
+        .with_help(help));
     }
-    // Check if the left type is a hashmap or an interface.
-    else if left_type.is_hashmap() || left_type.is_interface() {
+
+    // Check if the left type is a hashmap, an interface, a class instance or an enum.
+    if left_type.is_hashmap() ||
+      left_type.is_interface() ||
+      left_type.is_instance() ||
+      left_type.is_enum() ||
+      left_type.is_enum_variant() {
+      if let Some(right) = infix.get_right() {
+        check_member_access(&left_type, right.token(), environment)?;
+      }
+
       for (key, value) in left_type.get_methods() {
         right_environment.store.set_type(key, value);
       }
     }
 
-    // Check if `from_std` is not empty.
-    if !from_std.is_empty() {
-      if let Some(data_type) = environment.store.get_type(&from_std.to_string()) {
-        if data_type.is_hashmap() {
-          for (key, value) in data_type.get_methods() {
-            right_environment.store.set_type(key, value);
-          }
-        }
+    // Add the native members of the left type.
+    if let Some(data_type) = get_std_interface(&left_type, environment) {
+      let mut generics: HashMap<String, String> = HashMap::new();
+
+      // The `T` generic of the array methods is the data type of the elements.
+      if let Some(array) = left_type.get_type().get_array() {
+        generics.insert(String::from("T"), array.get_type().value);
+      }
+
+      for (key, value) in data_type.get_methods() {
+        right_environment.store.set_type(key, value.replace_generics(&generics));
       }
     }
   }
@@ -131,6 +177,28 @@ pub fn check(
   if infix.is_type() {
     let right_token = infix.get_right_type().unwrap();
 
+    // Check if the right type is an identifier.
+    if right_token.token.is_identifier() {
+      match environment.store.get_type(&right_token.value) {
+        Some(ttype) => {
+          if !ttype.is_enum() && !ttype.is_class() {
+            return Err(Error::from_token(
+              format!("`{}` is not an enum or a class.", right_token.value),
+              right_token,
+            ));
+          }
+
+          return Ok(TTypes::new_type(Types::BOOLEAN, String::from("boolean"), infix.get_token()));
+        },
+        None => {
+          return Err(Error::from_token(
+            format!("`{}` identifier not found.", right_token.value),
+            right_token,
+          ).with_code(Codes::IDENTIFIERNOTFOUND));
+        },
+      }
+    }
+
     match get_ttypes_from_token(right_token.clone(), right_token.clone()) {
       Some(token) => {
         right_type = token;
@@ -145,6 +213,11 @@ pub fn check(
   } else {
     match check_expression(&infix.get_right().unwrap(), &mut right_environment) {
       Ok(token) => {
+        // Do not report more errors for a failed binding.
+        if token.is_error() {
+          return Ok(token);
+        }
+
         right_type = token;
       },
       Err(error) => {
@@ -218,7 +291,7 @@ pub fn check(
         return Err(Error::from_token(
           format!("`{}` not satisfied the `{}` data type.", right_type.get_value(), left_type.get_value()),
           right_type.get_token(),
-        ));
+        ).with_code(Codes::NOTSATISFIED));
       }
 
       return Ok(left_type);
@@ -239,7 +312,7 @@ pub fn check(
   else if infix.is_infix() && left_type.clone().is_none() {
     // Check if the token is 'in'.
     if infix.get_token().token.expect_keyword(&Keywords::IN) {
-      if right_type.is_array() && right_type.get_type().get_array().is_some() {
+      if (right_type.is_array() && right_type.get_type().get_array().is_some()) || right_type.is_enum() {
         return Ok(TTypes::new_for_in(
           right_type.get_type(),
           right_type.get_value(),
@@ -249,7 +322,7 @@ pub fn check(
       }
 
       return Err(Error::from_token(
-        String::from("expect an array expression."),
+        String::from("expect an array or an enum expression."),
         infix.get_right().unwrap().token(),
       ));
     }
@@ -305,7 +378,8 @@ pub fn check(
     if (
       infix.get_token().token.expect_sign(&Signs::MINUSASSIGN) ||
       infix.get_token().token.expect_sign(&Signs::MULTIPLYASSIGN) ||
-      infix.get_token().token.expect_sign(&Signs::DIVIDEASSIGN)
+      infix.get_token().token.expect_sign(&Signs::DIVIDEASSIGN) ||
+      infix.get_token().token.expect_sign(&Signs::MODULEASSIGN)
     ) && left_type.get_type() != Types::NUMBER {
       return Err(Error::from_token(
         format!("`{}` is not a number.", infix.get_left().string()),
@@ -320,7 +394,7 @@ pub fn check(
       ));
     }
 
-    if equal_types(left_type.get_type(), right_type.get_type()) {
+    if equal_ttypes(left_type.get_type(), &right_type) {
       return Ok(right_type);
     }
 
@@ -331,7 +405,7 @@ pub fn check(
         left_type.get_value(),
       ),
       infix.get_right().unwrap().token(),
-    ));
+    ).with_code(Codes::NOTSATISFIED));
   }
 
   Err(Error::from_token(
@@ -1,6 +1,7 @@
 use crate::{
   Environment,
   typechecker::{
+    check_assignable,
     check_expression,
     TTypes,
   },
@@ -22,6 +23,12 @@ pub fn check(
 ) -> Result<TTypes, Error> {
   let right_type;
 
+  // Check if the right expression of a `++` or a `--` can be changed.
+  if prefix.get_token().token.expect_sign(&Signs::PLUSPLUS) ||
+    prefix.get_token().token.expect_sign(&Signs::MINUSMINUS) {
+    check_assignable(&prefix.get_right(), environment)?;
+  }
+
   match check_expression(&prefix.get_right(), environment) {
     Ok(token) => {
       right_type = token;
@@ -31,6 +38,11 @@ pub fn check(
     },
   }
 
+  // Do not report more errors for a failed binding.
+  if right_type.is_error() {
+    return Ok(right_type);
+  }
+
   if prefix.get_token().token.expect_sign(&Signs::MINUS) {
     if right_type.get_type() != Types::NUMBER {
       return Err(Error::from_token(
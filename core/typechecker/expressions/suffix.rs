@@ -1,6 +1,7 @@
 use crate::{
   Environment,
   typechecker::{
+    check_assignable,
     check_expression,
     TTypes,
   },
@@ -16,8 +17,12 @@ pub fn check(
   suffix: &Suffix,
   environment: &mut Environment,
 ) -> Result<TTypes, Error> {
+  // Check if the left expression can be changed.
+  check_assignable(&suffix.get_left(), environment)?;
+
   match check_expression(&suffix.get_left(), environment) {
-    Ok(ttype) => if ttype.get_type() != Types::NUMBER {
+    // Do not report more errors for a failed binding.
+    Ok(ttype) => if !ttype.is_error() && ttype.get_type() != Types::NUMBER {
       Err(Error::from_token(
         String::from("is not a valid number."),
         suffix.get_left().token(),
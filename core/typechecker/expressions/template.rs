@@ -0,0 +1,76 @@
+use crate::{
+  Environment,
+  typechecker::{
+    check_expression,
+    get_identifier_ttype,
+    TTypes,
+  },
+};
+
+use sflyn_parser::{
+  Error,
+  Expression,
+  Template,
+  tokens::Types,
+};
+
+/// Check if the values of the data type have a `toString` method.
+fn has_to_string(data_type: &TTypes, environment: &Environment) -> bool {
+  // Check if the data type is a class or an enum, not a value of them.
+  if data_type.is_class() || data_type.is_enum() || data_type.is_function() {
+    return false;
+  }
+
+  // Check if the data type has its own `toString` method.
+  if data_type.is_hashmap() || data_type.is_interface() || data_type.is_instance() {
+    return data_type.get_methods().contains_key("toString");
+  }
+
+  if data_type.is_enum_variant() {
+    return true;
+  }
+
+  has_type_to_string(data_type.get_type(), environment)
+}
+
+fn has_type_to_string(data_type: Types, environment: &Environment) -> bool {
+  match data_type {
+    Types::NULL | Types::STRING | Types::NUMBER | Types::BOOLEAN | Types::ARRAY(_) => true,
+    Types::VOID | Types::FUNCTION(_) | Types::HASHMAP(_) => false,
+    Types::UNION(union) => union.get_types().iter().all(|union_type| {
+      match union_type.token.get_type() {
+        Some(union_data_type) => has_type_to_string(union_data_type, environment),
+        None => match get_identifier_ttype(&union_type.value, environment) {
+          Some(ttype) => has_to_string(&ttype, environment),
+          None => false,
+        },
+      }
+    }),
+    // The generics are checked where they are replaced.
+    Types::GENERIC(_) => true,
+  }
+}
+
+pub fn check(
+  template: &Template,
+  environment: &mut Environment,
+) -> Result<TTypes, Error> {
+  for part in template.get_parts().iter() {
+    match check_expression(part, environment) {
+      Ok(data_type) => {
+        // Check if the part can be converted to a string.
+        if !has_to_string(&data_type, environment) {
+          return Err(Error::from_token(
+            format!("`{}` can not be converted to a string.", part.string()),
+            part.token(),
+          ).with_help(format!("`{}` does not have a `toString` method.", data_type.get_value())));
+        }
+      },
+      Err(error) => {
+        return Err(error);
+      },
+    }
+  }
+
+  Ok(TTypes::new_type(Types::STRING, String::from("string"), template.get_token()))
+}
@@ -1,10 +1,17 @@
 mod block;
+mod class;
+mod do_while;
+mod enum_s;
 mod for_s;
 mod function;
 mod if_else;
 mod import;
 mod interface;
+mod switch;
+mod throw;
+mod try_s;
 mod variable;
+mod while_s;
 
 use crate::{
   Environment,
@@ -18,9 +25,42 @@ use sflyn_parser::{
   Error,
   Statement,
   Statements,
-  tokens::Types,
+  tokens::{
+    Token,
+    Types,
+  },
 };
 
+/// Set the names declared by a failed statement as error types, so their uses do not report
+/// more errors.
+pub fn recover_statement(
+  statement: &Statements,
+  environment: &mut Environment,
+) {
+  let name: Token;
+
+  if let Some(export) = statement.get_export() {
+    return recover_statement(&export.get_value(), environment);
+  } else if let Some(variable) = statement.get_variable() {
+    name = variable.get_name();
+  } else if let Some(function) = statement.get_function() {
+    name = function.get_name();
+  } else if let Some(class) = statement.get_class() {
+    name = class.get_name();
+  } else if let Some(interface) = statement.get_interface() {
+    name = interface.get_name();
+  } else if let Some(enum_s) = statement.get_enum() {
+    name = enum_s.get_name();
+  } else {
+    return;
+  }
+
+  // The failed declaration shadows the outer one, so its uses are not checked against it.
+  if !environment.store.has_own_type(&name.value) {
+    environment.store.set_type(name.value.clone(), TTypes::new_error(name));
+  }
+}
+
 pub fn check_statement(
   statement: &Box<Statements>,
   environment: &mut Environment,
@@ -30,6 +70,11 @@ pub fn check_statement(
     return block::check(&block_stmt, environment);
   }
 
+  // Class
+  if let Some(class_stmt) = statement.get_class() {
+    return class::check(&class_stmt, environment);
+  }
+
   // Continue and break
   if let Some(continue_break) = statement.get_continue_break() {
     return Ok(TTypes::new_type(
@@ -39,6 +84,16 @@ pub fn check_statement(
     ));
   }
 
+  // Do while
+  if let Some(do_while_stmt) = statement.get_do_while() {
+    return do_while::check(&do_while_stmt, environment);
+  }
+
+  // Enum
+  if let Some(enum_stmt) = statement.get_enum() {
+    return enum_s::check(&enum_stmt, environment);
+  }
+
   // Export
   if let Some(export) = statement.get_export() {
     return check_statement(&export.get_value(), environment);
@@ -83,11 +138,31 @@ pub fn check_statement(
     return Ok(TTypes::new_type(Types::VOID, String::from("void"), return_stmt.get_token()));
   }
 
+  // Switch
+  if let Some(switch_stmt) = statement.get_switch() {
+    return switch::check(&switch_stmt, environment);
+  }
+
+  // Throw
+  if let Some(throw_stmt) = statement.get_throw() {
+    return throw::check(&throw_stmt, environment);
+  }
+
+  // Try
+  if let Some(try_stmt) = statement.get_try() {
+    return try_s::check(&try_stmt, environment);
+  }
+
   // Variable
   if let Some(variable_stmt) = statement.get_variable() {
     return variable::check(&variable_stmt, environment);
   }
 
+  // While
+  if let Some(while_stmt) = statement.get_while() {
+    return while_s::check(&while_stmt, environment);
+  }
+
   // Default
   Err(Error::from_token(
     String::from("unknown statement."),
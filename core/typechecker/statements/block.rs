@@ -3,7 +3,9 @@ use crate::{
   typechecker::{
     check_statement,
     equal_types,
+    recover_statement,
     TTypes,
+    union_ttypes,
   },
 };
 
@@ -14,37 +16,80 @@ use sflyn_parser::{
   tokens::Types,
 };
 
+use super::if_else::get_guard_types;
+
 pub fn check(
   block: &Block,
   environment: &mut Environment,
 ) -> Result<TTypes, Error> {
   let mut return_token: Option<TTypes> = None;
+  let mut has_errors: bool = false;
 
   for statement in block.get_statements().iter() {
     // Get the token for the current statement.
     match check_statement(statement, environment) {
       Ok(token) => {
-        if statement.get_return().is_some() || statement.get_if_else().is_some() {
+        // Narrow the union types after the if guards.
+        if let Some(if_else) = statement.get_if_else() {
+          for (name, ttype) in get_guard_types(&if_else, environment) {
+            environment.store.set_type(name, ttype);
+          }
+        }
+
+        // An if else, a switch or a try without returns does not change the block type.
+        if (
+          statement.get_if_else().is_some() ||
+          statement.get_switch().is_some() ||
+          statement.get_try().is_some()
+        ) && token.get_type() == Types::VOID {
+          continue;
+        }
+
+        if statement.get_return().is_some() ||
+          statement.get_throw().is_some() ||
+          statement.get_if_else().is_some() ||
+          statement.get_switch().is_some() ||
+          statement.get_try().is_some() {
           if let Some(rtoken) = return_token.clone() {
+            // Keep the error data type, its errors are already reported.
+            if rtoken.is_error() || token.is_error() {
+              return_token = Some(if rtoken.is_error() { rtoken } else { token });
+              continue;
+            }
+
+            // A throw before does not give the block type.
+            if rtoken.get_value() == "any" {
+              return_token = Some(token);
+              continue;
+            }
+
             if equal_types(rtoken.get_type(), token.get_type()) || token.get_value() == "any" {
               continue;
             }
 
-            return Err(Error::from_token(
-              format!("`{}` not satisfied the `{}` data type.", token.get_token().value, rtoken.get_token().value),
-              token.get_token(),
-            ));
+            // Use the union of both data types.
+            return_token = Some(union_ttypes(rtoken, token));
+            continue;
           }
 
           return_token = Some(token);
         }
       },
       Err(error) => {
-        return Err(error);
+        // Keep checking the next statements.
+        environment.add_error(error);
+        recover_statement(statement, environment);
+
+        has_errors = true;
       },
     }
   }
 
+  // The block data type is unknown when it has errors.
+  if has_errors {
+    return Ok(TTypes::new_error(block.get_token()));
+  }
+
   if let Some(token) = return_token {
     return Ok(token);
   }
@@ -0,0 +1,182 @@
+use crate::{
+  Environment,
+  Store,
+  typechecker::{
+    function_arguments_to_string,
+    TTypes,
+  },
+};
+
+use sflyn_parser::{
+  Class,
+  Codes,
+  Error,
+  Statement,
+  tokens::{
+    HashMap,
+    Keywords,
+    Token,
+    Types,
+  },
+};
+
+use super::{
+  function,
+  variable,
+};
+
+use std::collections::HashMap as HashMapSTD;
+
+/// Get the keywords from the modifiers tokens.
+pub fn get_modifiers(tokens: Vec<Token>) -> Vec<Keywords> {
+  let mut modifiers: Vec<Keywords> = Vec::new();
+
+  for token in tokens.iter() {
+    if let Some(keyword) = token.token.get_keyword() {
+      modifiers.push(keyword);
+    }
+  }
+
+  modifiers
+}
+
+pub fn check(
+  class: &Class,
+  environment: &mut Environment,
+) -> Result<TTypes, Error> {
+  // Check if the class name is already in use.
+  if environment.store.get_type(&class.get_name().value).is_some() {
+    return Err(Error::from_token(
+      format!("`{}` is already in use.", class.get_name().value),
+      class.get_name(),
+    ).with_code(Codes::ALREADYINUSE));
+  }
+
+  // Create a new closed environment.
+  let mut class_environment: Environment = environment.clone();
+
+  class_environment.store = Store::from_store(environment.store.clone());
+
+  // Add the constructor arguments to the class environment.
+  function_arguments_to_string(class.get_arguments(), environment, &mut class_environment)?;
+
+  let mut values: Vec<String> = Vec::new();
+  let mut methods: HashMapSTD<String, TTypes> = HashMapSTD::new();
+  let mut modifiers: HashMapSTD<String, Vec<Keywords>> = HashMapSTD::new();
+
+  // Add the constructor arguments as class members.
+  for argument in class.get_arguments().iter() {
+    let name: String = argument.token().value;
+
+    if let Some(ttype) = class_environment.store.get_type(&name) {
+      values.push(format!("{}: {}", name, ttype.get_value()));
+      methods.insert(name, ttype);
+    }
+  }
+
+  // Add the class to the environment store before the members, so the members can use the
+  // class data type. The members are added when they are checked.
+  let class_type = TTypes::new_class(
+    Types::HASHMAP(HashMap::new(format!("{{{}}}", values.join(", ")))),
+    class.get_name().value,
+    class.get_token(),
+    class.get_arguments(),
+    methods.clone(),
+    modifiers.clone(),
+  );
+
+  environment.store.set_type(class.get_name().value, class_type.clone());
+  class_environment.store.set_type(class.get_name().value, class_type);
+
+  // Check the class fields.
+  for field in class.get_fields().iter() {
+    if methods.contains_key(&field.get_name().value) {
+      return Err(Error::from_token(
+        format!("`{}` is already in use.", field.get_name().value),
+        field.get_name(),
+      ).with_code(Codes::ALREADYINUSE));
+    }
+
+    let mut field_modifiers: Vec<Keywords> = get_modifiers(class.get_modifiers(&field.get_name().value));
+
+    // A const field can not be changed after the construction.
+    if field.get_token().token.expect_keyword(&Keywords::CONST) &&
+      !field_modifiers.contains(&Keywords::READONLY) {
+      field_modifiers.push(Keywords::READONLY);
+    }
+
+    match variable::check(field, &mut class_environment) {
+      Ok(ttype) => {
+        values.push(format!("{}: {}", field.get_name().value, ttype.get_value()));
+        methods.insert(field.get_name().value, ttype);
+      },
+      Err(error) => {
+        return Err(error);
+      },
+    }
+
+    // The methods read the fields by their names, so the readonly ones can not be changed by them.
+    if field_modifiers.contains(&Keywords::READONLY) {
+      class_environment.store.set_readonly(field.get_name().value, field.get_name());
+    }
+
+    modifiers.insert(field.get_name().value, field_modifiers);
+  }
+
+  // Get the class methods data types.
+  for method in class.get_methods().iter() {
+    if methods.contains_key(&method.get_name().value) {
+      return Err(Error::from_token(
+        format!("`{}` is already in use.", method.get_name().value),
+        method.get_name(),
+      ).with_code(Codes::ALREADYINUSE));
+    }
+
+    let mut method_environment: Environment = class_environment.clone();
+
+    method_environment.store = Store::from_store(class_environment.store.clone());
+
+    modifiers.insert(method.get_name().value, get_modifiers(class.get_modifiers(&method.get_name().value)));
+
+    match function::get_ttype(method, &mut class_environment, &mut method_environment) {
+      Ok(ttype) => {
+        values.push(format!("{}: {}", method.get_name().value, ttype.get_value()));
+        methods.insert(method.get_name().value, ttype);
+      },
+      Err(error) => {
+        return Err(error);
+      },
+    }
+  }
+
+  let value = format!("{{{}}}", values.join(", "));
+
+  let ttype = TTypes::new_class(
+    Types::HASHMAP(HashMap::new(value)),
+    class.get_name().value,
+    class.get_token(),
+    class.get_arguments(),
+    methods,
+    modifiers,
+  );
+
+  // Replace the class data type with the members, so methods can create new instances.
+  environment.store.set_type(class.get_name().value, ttype.clone());
+  class_environment.store.set_type(class.get_name().value, ttype.clone());
+
+  // Add `this` to the class environment.
+  class_environment.store.set_type(String::from("this"), ttype.to_instance(class.get_token()));
+
+  // Check the class methods bodies.
+  for method in class.get_methods().iter() {
+    let mut method_environment: Environment = class_environment.clone();
+
+    method_environment.store = Store::from_store(class_environment.store.clone());
+
+    function_arguments_to_string(method.get_arguments(), &mut class_environment, &mut method_environment)?;
+
+    function::check_body(method, &mut method_environment)?;
+  }
+
+  Ok(ttype)
+}
@@ -0,0 +1,46 @@
+use crate::{
+  Environment,
+  Store,
+  typechecker::{
+    check_expression,
+    check_statement,
+    TTypes,
+  },
+};
+
+use sflyn_parser::{
+  DoWhile,
+  Error,
+  tokens::Types,
+};
+
+pub fn check(
+  do_while: &DoWhile,
+  environment: &mut Environment,
+) -> Result<TTypes, Error> {
+  // Create a new closed environment.
+  let mut do_while_environment: Environment = environment.clone();
+
+  do_while_environment.store = Store::from_store(environment.store.clone());
+
+  // Get the ttypes from the do while body.
+  let body_type: TTypes = check_statement(&do_while.get_body(), &mut do_while_environment)?;
+
+  // Check the condition expression.
+  match check_expression(&do_while.get_condition(), environment) {
+    Ok(token) => {
+      // The failed bindings are not reported again.
+      if !token.is_error() && token.get_type() != Types::BOOLEAN {
+        return Err(Error::from_token(
+          String::from("the condition is not a `boolean`."),
+          do_while.get_condition().token(),
+        ));
+      }
+    },
+    Err(error) => {
+      return Err(error);
+    },
+  }
+
+  Ok(body_type)
+}
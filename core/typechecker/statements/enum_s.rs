@@ -0,0 +1,136 @@
+use crate::{
+  Environment,
+  typechecker::{
+    check_expression,
+    TTypes,
+  },
+};
+
+use sflyn_parser::{
+  Codes,
+  Enum,
+  Error,
+  Statement,
+  tokens::{
+    HashMap,
+    Types,
+  },
+};
+
+use std::collections::HashMap as HashMapSTD;
+
+pub fn check(
+  enum_s: &Enum,
+  environment: &mut Environment,
+) -> Result<TTypes, Error> {
+  // Check if the enum name is already in use.
+  if environment.store.get_type(&enum_s.get_name().value).is_some() {
+    return Err(Error::from_token(
+      format!("`{}` is already in use.", enum_s.get_name().value),
+      enum_s.get_name(),
+    ).with_code(Codes::ALREADYINUSE));
+  }
+
+  let name: String = enum_s.get_name().value;
+
+  // Use the enum name as the only hashmap key, so two enums never have the same data type.
+  let data_type: Types = Types::HASHMAP(HashMap::from_value(&format!("{{{}: enum}}", name)).unwrap());
+
+  let mut value_type: Option<TTypes> = None;
+
+  for variant in enum_s.get_variants().iter() {
+    match variant.get_value() {
+      Some(value) => {
+        match check_expression(&value, environment) {
+          Ok(ttype) => {
+            // Check if the value is a number or a string.
+            if ttype.get_type() != Types::NUMBER && ttype.get_type() != Types::STRING {
+              return Err(Error::from_token(
+                String::from("enum values only can be numbers or strings."),
+                value.token(),
+              ));
+            }
+
+            // Check if the value has the same data type as the previous values.
+            if let Some(previous) = value_type.clone() {
+              if previous.get_type() != ttype.get_type() {
+                return Err(Error::from_token(
+                  format!("`{}` not satisfied the `{}` data type.", ttype.get_value(), previous.get_value()),
+                  value.token(),
+                ).with_code(Codes::NOTSATISFIED));
+              }
+            }
+
+            value_type = Some(ttype);
+          },
+          Err(error) => {
+            return Err(error);
+          },
+        }
+      },
+      None => {
+        // Check if the previous values are strings.
+        if let Some(previous) = value_type.clone() {
+          if previous.get_type() == Types::STRING {
+            return Err(Error::from_token(
+              format!("`{}` must have a value.", variant.get_token().value),
+              variant.get_token(),
+            ));
+          }
+        }
+      },
+    }
+  }
+
+  let mut variant_methods: HashMapSTD<String, TTypes> = HashMapSTD::new();
+
+  variant_methods.insert(
+    String::from("name"),
+    TTypes::new_type(Types::STRING, String::from("string"), enum_s.get_token()),
+  );
+
+  variant_methods.insert(
+    String::from("value"),
+    match value_type {
+      Some(ttype) => TTypes::new_type(ttype.get_type(), ttype.get_value(), enum_s.get_token()),
+      None => TTypes::new_type(Types::NUMBER, String::from("number"), enum_s.get_token()),
+    },
+  );
+
+  let mut names: Vec<String> = Vec::new();
+  let mut methods: HashMapSTD<String, TTypes> = HashMapSTD::new();
+
+  for variant in enum_s.get_variants().iter() {
+    // Check if the variant name is already in use.
+    if names.contains(&variant.get_token().value) {
+      return Err(Error::from_token(
+        format!("`{}` is already in use.", variant.get_token().value),
+        variant.get_token(),
+      ).with_code(Codes::ALREADYINUSE));
+    }
+
+    names.push(variant.get_token().value);
+
+    methods.insert(
+      variant.get_token().value,
+      TTypes::new_enum_variant(
+        data_type.clone(),
+        name.clone(),
+        variant.get_token(),
+        variant_methods.clone(),
+      ),
+    );
+  }
+
+  let ttype = TTypes::new_enum(
+    data_type,
+    name.clone(),
+    enum_s.get_token(),
+    names,
+    methods,
+  );
+
+  environment.store.set_type(name, ttype.clone());
+
+  Ok(ttype)
+}
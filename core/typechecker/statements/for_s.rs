@@ -4,6 +4,8 @@ use crate::{
   typechecker::{
     check_expression,
     check_statement,
+    get_identifier_ttype,
+    get_identifier_value_ttype,
     get_ttypes_from_token,
     TTypes,
   },
@@ -33,9 +35,17 @@ pub fn check(
       if obj.is_for_in() && obj.get_type().get_array().is_some() {
         let right_array: Array = obj.get_type().get_array().unwrap();
 
-        if let Some(ttype) = get_ttypes_from_token(right_array.get_type(), obj.get_token()) {
+        // The elements can be instances of a class or variants of an enum.
+        let element_type = get_ttypes_from_token(right_array.get_type(), obj.get_token()).or_else(|| {
+          get_identifier_ttype(&right_array.get_type().value, &for_environment)
+            .and_then(|ttype| get_identifier_value_ttype(&ttype, obj.get_token()))
+        });
+
+        if let Some(ttype) = element_type {
           let mut new_environment = for_environment.clone();
 
+          new_environment.store = Store::from_store(for_environment.store.clone());
+
           new_environment.store.set_type(
             obj.get_names()[0].clone(),
             ttype.clone(),
@@ -43,6 +53,22 @@ pub fn check(
 
           return check_statement(&for_s.get_body(), &mut new_environment);
         }
+      } else if obj.is_for_in() {
+        // Check if is an enum.
+        if let Some(enum_type) = for_environment.store.get_type(&obj.get_value()) {
+          if enum_type.is_enum() {
+            let mut new_environment = for_environment.clone();
+
+            new_environment.store = Store::from_store(for_environment.store.clone());
+
+            new_environment.store.set_type(
+              obj.get_names()[0].clone(),
+              enum_type.to_enum_variant(obj.get_token()),
+            );
+
+            return check_statement(&for_s.get_body(), &mut new_environment);
+          }
+        }
       } else if obj.is_for_of() && obj.get_type().get_hashmap().is_some() {
         if obj.get_names().len() == 2 {
           let hashmap: HashMap = obj.get_type().get_hashmap().unwrap();
@@ -51,6 +77,8 @@ pub fn check(
           for (_, value) in hashmap.get_items().iter() {
             let mut new_environment = for_environment.clone();
 
+            new_environment.store = Store::from_store(for_environment.store.clone());
+
             if let Some(ttype) = get_ttypes_from_token(value.clone(), value.clone()) {
               new_environment.store.set_type(
                 obj.get_names()[0].clone(),
@@ -3,39 +3,31 @@ use crate::{
   Store,
   typechecker::{
     check_statement,
-    equal_types,
+    equal_ttypes,
     function_arguments_to_string,
+    get_identifier_ttype,
+    satisfies_identifier_ttype,
     TTypes,
   },
 };
 
 use sflyn_parser::{
+  Codes,
   Error,
   Function,
   Statement,
   tokens::Token,
 };
 
-pub fn check(
+/// Get the function data type from its arguments and its return data type.
+pub fn get_ttype(
   function: &Function,
   environment: &mut Environment,
+  function_environment: &mut Environment,
 ) -> Result<TTypes, Error> {
-  // Check if the function name is already in use.
-  if environment.store.get_type(&function.get_name().value).is_some() {
-    return Err(Error::from_token(
-      format!("`{}` is already in use.", function.get_name().value),
-      function.get_name(),
-    ));
-  }
-
-  // Create a new closed environment.
-  let mut function_environment: Environment = environment.clone();
-
-  function_environment.store = Store::from_store(environment.store.clone());
-
   let arguments: Vec<String>;
 
-  match function_arguments_to_string(function.get_arguments(), environment, &mut function_environment) {
+  match function_arguments_to_string(function.get_arguments(), environment, function_environment) {
     Ok(args) => {
       arguments = args;
     },
@@ -44,18 +36,76 @@ pub fn check(
     },
   }
 
+  // Check if the return data type exists, so the failed signatures are not added to the environment.
+  let return_type: Token = function.get_type();
+
+  if return_type.token.is_identifier() && get_identifier_ttype(&return_type.value, function_environment).is_none() {
+    return Err(Error::from_token(
+      format!("`{}` identifier not found.", return_type.value),
+      return_type,
+    ).with_code(Codes::IDENTIFIERNOTFOUND));
+  }
+
+  let mut generics: Vec<String> = Vec::new();
+
+  for generic in function.get_generics().iter() {
+    generics.push(generic.value.clone());
+  }
+
+  let value = format!("({}) => {}", arguments.join(", "), function.get_type().value);
+  let token = Token::from_value(value.as_str(), 0, 0);
+
+  if token.token.get_type().is_none() {
+    return Err(Error::from_token(
+      String::from("is not a valid function."),
+      function.get_token(),
+    ));
+  }
+
+  Ok(TTypes::new_function(
+    token.token.get_type().unwrap(),
+    token.value,
+    function.get_token(),
+    generics,
+    function.get_arguments(),
+  ))
+}
+
+/// Check if the function body satisfies the function return data type.
+pub fn check_body(
+  function: &Function,
+  function_environment: &mut Environment,
+) -> Result<(), Error> {
   // Get the function data type.
   let data_type: Token = function.get_type();
 
   // Get the ttypes from the function body.
-  match check_statement(&function.get_body(), &mut function_environment) {
+  match check_statement(&function.get_body(), function_environment) {
     Ok(token) => {
-      if let Some(ttoken) = data_type.token.get_type() {
-        if !equal_types(ttoken, token.get_type()) && token.get_value() != "any" {
+      // Check if the data type is a class, an enum or an interface.
+      if data_type.token.is_identifier() {
+        match get_identifier_ttype(&data_type.value, function_environment) {
+          Some(ttype) => {
+            if !satisfies_identifier_ttype(&ttype, &token) && token.get_value() != "any" {
+              return Err(Error::from_token(
+                format!("`{}` not satisfied the `{}` data type.", token.get_token().value, data_type.value),
+                token.get_token(),
+              ).with_code(Codes::NOTSATISFIED));
+            }
+          },
+          None => {
+            return Err(Error::from_token(
+              format!("`{}` identifier not found.", data_type.value),
+              data_type,
+            ).with_code(Codes::IDENTIFIERNOTFOUND));
+          },
+        }
+      } else if let Some(ttoken) = data_type.token.get_type() {
+        if !equal_ttypes(ttoken, &token) && token.get_value() != "any" {
           return Err(Error::from_token(
             format!("`{}` not satisfied the `{}` data type.", token.get_token().value, data_type.value),
             token.get_token(),
-          ));
+          ).with_code(Codes::NOTSATISFIED));
         }
       } else {
         return Err(Error::from_token(
@@ -69,24 +119,33 @@ pub fn check(
     },
   }
 
-  let value = format!("({}) => {}", arguments.join(", "), data_type.value);
-  let token = Token::from_value(value.as_str(), 0, 0);
+  Ok(())
+}
 
-  if token.token.get_type().is_none() {
+pub fn check(
+  function: &Function,
+  environment: &mut Environment,
+) -> Result<TTypes, Error> {
+  // Check if the function name is already in use.
+  if environment.store.get_type(&function.get_name().value).is_some() {
     return Err(Error::from_token(
-      String::from("is not a valid function."),
-      function.get_token(),
-    ));
+      format!("`{}` is already in use.", function.get_name().value),
+      function.get_name(),
+    ).with_code(Codes::ALREADYINUSE));
   }
 
-  let ttype = TTypes::new_function(
-    token.token.get_type().unwrap(),
-    token.value,
-    function.get_token(),
-    function.get_arguments(),
-  );
+  // Create a new closed environment.
+  let mut function_environment: Environment = environment.clone();
 
+  function_environment.store = Store::from_store(environment.store.clone());
+
+  let ttype: TTypes = get_ttype(function, environment, &mut function_environment)?;
+
+  // Add the function before the body, so the body can call it.
   environment.store.set_type(function.get_name().value, ttype.clone());
+  function_environment.store.set_type(function.get_name().value, ttype.clone());
+
+  check_body(function, &mut function_environment)?;
 
   Ok(ttype)
 }
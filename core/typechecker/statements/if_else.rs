@@ -4,27 +4,85 @@ use crate::{
     check_expression,
     check_statement,
     equal_types,
+    get_narrowed_types,
+    narrow_environment,
+    scope_environment,
     TTypes,
   },
 };
 
 use sflyn_parser::{
+  Codes,
   Error,
   IfElse,
   Statement,
+  Statements,
   tokens::Types,
 };
 
+/// Check if the statement returns or throws in all the cases.
+fn always_returns(statement: &Statements) -> bool {
+  if statement.get_return().is_some() || statement.get_throw().is_some() {
+    return true;
+  }
+
+  if let Some(block) = statement.get_block() {
+    return block.get_statements().iter()
+      .any(|statement| statement.get_return().is_some() || statement.get_throw().is_some());
+  }
+
+  false
+}
+
+/// Get the narrowed types after an if without alternative where all the consequences return,
+/// like `if (name == null) { return; }`.
+pub fn get_guard_types(
+  if_else: &IfElse,
+  environment: &Environment,
+) -> Vec<(String, TTypes)> {
+  let mut rest_types: Vec<(String, TTypes)> = Vec::new();
+
+  if if_else.get_alternative().is_some() {
+    return rest_types;
+  }
+
+  for condition in if_else.get_conditions().iter() {
+    if !always_returns(&condition.get_consequence()) {
+      return Vec::new();
+    }
+
+    let narrowed_types = match narrow_environment(environment, &rest_types) {
+      Some(condition_environment) => get_narrowed_types(&condition.get_condition(), &condition_environment),
+      None => get_narrowed_types(&condition.get_condition(), environment),
+    };
+
+    if let Some((name, _, Some(rest))) = narrowed_types {
+      rest_types.push((name, rest));
+    }
+  }
+
+  rest_types
+}
+
 pub fn check(
   if_else: &IfElse,
   environment: &mut Environment,
 ) -> Result<TTypes, Error> {
   let mut data_type: Option<TTypes> = None;
 
+  // Types narrowed by the previous conditions.
+  let mut rest_types: Vec<(String, TTypes)> = Vec::new();
+
   for condition in if_else.get_conditions().iter() {
-    match check_expression(&condition.get_condition(), environment) {
+    let condition_type = match narrow_environment(environment, &rest_types) {
+      Some(mut condition_environment) => check_expression(&condition.get_condition(), &mut condition_environment),
+      None => check_expression(&condition.get_condition(), environment),
+    };
+
+    match condition_type {
       Ok(token) => {
-        if token.get_type() != Types::BOOLEAN {
+        // The failed bindings are not reported again.
+        if !token.is_error() && token.get_type() != Types::BOOLEAN {
           return Err(Error::from_token(
             String::from("the condition is not a `boolean`."),
             condition.get_token(),
@@ -36,14 +94,41 @@ pub fn check(
       },
     }
 
-    match check_statement(&condition.get_consequence(), environment) {
+    let mut consequence_types: Vec<(String, TTypes)> = rest_types.clone();
+
+    // Narrow the union types with the condition.
+    let narrowed_types = match narrow_environment(environment, &rest_types) {
+      Some(condition_environment) => get_narrowed_types(&condition.get_condition(), &condition_environment),
+      None => get_narrowed_types(&condition.get_condition(), environment),
+    };
+
+    if let Some((name, narrowed, rest)) = narrowed_types {
+      consequence_types.push((name.clone(), narrowed));
+
+      if let Some(rest) = rest {
+        rest_types.push((name, rest));
+      }
+    }
+
+    let consequence_type = check_statement(
+      &condition.get_consequence(),
+      &mut scope_environment(environment, &consequence_types),
+    );
+
+    match consequence_type {
       Ok(token) => {
+        // A consequence that throws does not give the if type.
+        if data_type.clone().is_some_and(|data_type| data_type.get_value() == "any") {
+          data_type = Some(token);
+          continue;
+        }
+
         if data_type.clone().is_some() {
           if !equal_types(data_type.clone().unwrap().get_type(), token.get_type()) && token.get_value() != "any" {
             return Err(Error::from_token(
               format!("`{}` not satisfied the `{}` data type.", token.get_value(), data_type.unwrap().get_value()),
               token.get_token(),
-            ));
+            ).with_code(Codes::NOTSATISFIED));
           }
 
           continue;
@@ -65,13 +150,19 @@ pub fn check(
   }
 
   if let Some(alternative) = if_else.get_alternative() {
-    match check_statement(&alternative, environment) {
+    let alternative_type = check_statement(&alternative, &mut scope_environment(environment, &rest_types));
+
+    match alternative_type {
       Ok(token) => {
+        if data_type.clone().unwrap().get_value() == "any" {
+          return Ok(token);
+        }
+
         if !equal_types(data_type.clone().unwrap().get_type(), token.get_type()) && token.get_value() != "any" {
           return Err(Error::from_token(
             format!("`{}` not satisfied the `{}` data type.", token.get_value(), data_type.unwrap().get_value()),
             token.get_token(),
-          ));
+          ).with_code(Codes::NOTSATISFIED));
         }
       },
       Err(error) => {
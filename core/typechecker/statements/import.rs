@@ -9,6 +9,7 @@ use crate::{
 };
 
 use sflyn_parser::{
+  Codes,
   Error,
   Expression,
   Import,
@@ -135,6 +136,7 @@ pub fn check(
 
   if import.get_modules().len() == 0 {
     for (key, value) in methods.iter() {
+      environment.store.set_import(key.clone(), import.get_token());
       environment.store.set_type(key.clone(), value.clone());
     }
   } else {
@@ -142,6 +144,7 @@ pub fn check(
       // Check if the module is an identifier.
       if let Some(identifier) = module.get_identifier() {
         if let Some(token) = import_environment.store.get_type(&identifier.get_value()) {
+          environment.store.set_import(identifier.get_value(), identifier.get_token());
           environment.store.set_type(identifier.get_value(), token);
           continue;
         }
@@ -149,7 +152,7 @@ pub fn check(
         return Err(Error::from_token(
           format!("`{}` identifier not found in `{}`.", identifier.get_value(), path_to),
           identifier.get_token(),
-        ));
+        ).with_code(Codes::IDENTIFIERNOTFOUND));
       }
       // Check if the module is an infix.
       else if let Some(infix) = module.get_infix() {
@@ -160,6 +163,7 @@ pub fn check(
             // Get the type from the environment.
             if let Some(token) = import_environment.store.get_type(&left_identifier.get_value()) {
               if let Some(right_identifier) = infix.get_right().unwrap().get_identifier() {
+                environment.store.set_import(right_identifier.get_value(), right_identifier.get_token());
                 environment.store.set_type(right_identifier.get_value(), token);
                 continue;
               }
@@ -167,6 +171,7 @@ pub fn check(
             // Check if the left identifier is an `*`.
             else if left_identifier.get_value() == "*" {
               if let Some(right_identifier) = infix.get_right().unwrap().get_identifier() {
+                environment.store.set_import(right_identifier.get_value(), right_identifier.get_token());
                 environment.store.set_type(right_identifier.get_value(), ttype.clone());
                 continue;
               }
@@ -175,7 +180,7 @@ pub fn check(
             return Err(Error::from_token(
               format!("`{}` identifier not found in `{}`.", left_identifier.get_value(), path_to),
               left_identifier.get_token(),
-            ));
+            ).with_code(Codes::IDENTIFIERNOTFOUND));
           }
         }
 
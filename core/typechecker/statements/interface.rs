@@ -1,15 +1,27 @@
 use crate::{
   Environment,
-  typechecker::TTypes,
+  typechecker::{
+    get_ttypes_from_token,
+    TTypes,
+  },
 };
 
 use sflyn_parser::{
+  Codes,
   Error,
   Interface,
   Statement,
-  tokens::Token,
+  tokens::{
+    Keywords,
+    Token,
+    Tokens,
+    Types,
+    Union,
+  },
 };
 
+use super::class::get_modifiers;
+
 use std::collections::HashMap;
 
 pub fn check(
@@ -21,11 +33,18 @@ pub fn check(
     return Err(Error::from_token(
       format!("`{}` is already in use.", interface.get_name().value),
       interface.get_name(),
-    ));
+    ).with_code(Codes::ALREADYINUSE));
   }
 
+  let mut generics: Vec<String> = Vec::new();
   let mut values: Vec<String> = Vec::new();
+
+  for generic in interface.get_generics().iter() {
+    generics.push(generic.value.clone());
+  }
+
   let mut methods: HashMap<String, TTypes> = HashMap::new();
+  let mut modifiers: HashMap<String, Vec<Keywords>> = HashMap::new();
 
   for method in interface.get_methods().iter() {
     if method.get_type().token.get_type().is_none() {
@@ -35,16 +54,37 @@ pub fn check(
       ));
     }
 
-    values.push(format!("{}: {}", method.get_token().value, method.get_type().value));
+    let mut data_type: Token = method.get_type();
+
+    // Optional members can be null.
+    if method.is_optional() {
+      let union = Union::new(vec![
+        data_type.clone(),
+        Token::from_value("null", data_type.line, data_type.position),
+      ]);
 
-    methods.insert(
-      method.get_token().value,
-      TTypes::new_type(
-        method.get_type().token.get_type().unwrap(),
-        method.get_type().value,
+      data_type = Token::new(
+        Box::new(Tokens::TYPE(Types::UNION(union.clone()))),
+        union.get_value(),
+        data_type.line,
+        data_type.position,
+      );
+    }
+
+    values.push(format!("{}: {}", method.get_token().value, data_type.value));
+
+    modifiers.insert(method.get_token().value, get_modifiers(method.get_modifiers()));
+
+    let method_type: TTypes = match get_ttypes_from_token(data_type.clone(), method.get_token()) {
+      Some(method_type) => method_type,
+      None => TTypes::new_type(
+        data_type.token.get_type().unwrap(),
+        data_type.value,
         method.get_token(),
       ),
-    );
+    };
+
+    methods.insert(method.get_token().value, method_type);
   }
 
   let mut value = String::from("{");
@@ -65,7 +105,9 @@ pub fn check(
     token.token.get_type().unwrap(),
     value,
     interface.get_token(),
+    generics,
     methods,
+    modifiers,
   );
 
   environment.store.set_type(interface.get_name().value, ttype.clone());
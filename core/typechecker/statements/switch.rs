@@ -0,0 +1,196 @@
+use crate::{
+  Environment,
+  typechecker::{
+    check_expression,
+    check_statement,
+    equal_types,
+    get_identifier_ttype,
+    scope_environment,
+    TTypes,
+  },
+};
+
+use sflyn_parser::{
+  Codes,
+  Error,
+  Expression,
+  Expressions,
+  Statement,
+  Switch,
+  tokens::Types,
+};
+
+/// Get the literal value of a case expression.
+fn get_literal_value(expression: &Expressions) -> Option<String> {
+  if let Some(number) = expression.get_number() {
+    return Some(number.get_value().to_string());
+  }
+
+  if let Some(string) = expression.get_string() {
+    let value = string.get_value();
+
+    return Some(format!("\"{}\"", &value[1..value.len() - 1]));
+  }
+
+  if let Some(boolean) = expression.get_boolean() {
+    return Some(boolean.get_value().to_string());
+  }
+
+  if let Some(null) = expression.get_null() {
+    return Some(null.string());
+  }
+
+  None
+}
+
+/// Get the variant name of an `Enum->Variant` case expression.
+fn get_variant_name(expression: &Expressions, enum_name: &String) -> Option<String> {
+  let infix = expression.get_infix()?;
+
+  if !infix.is_method() || &infix.get_left().get_identifier()?.get_value() != enum_name {
+    return None;
+  }
+
+  Some(infix.get_right()?.get_identifier()?.get_value())
+}
+
+/// Get the variants of the enum value that are not covered by the cases of a switch without default.
+fn get_missing_variants(
+  switch: &Switch,
+  value_type: &TTypes,
+  environment: &Environment,
+) -> Vec<String> {
+  if !value_type.is_enum_variant() || switch.get_default().is_some() {
+    return Vec::new();
+  }
+
+  let enum_name: String = value_type.get_value();
+
+  let variants: Vec<String> = match get_identifier_ttype(&enum_name, environment) {
+    Some(enum_type) if enum_type.is_enum() => enum_type.get_names(),
+    _ => {
+      return Vec::new();
+    },
+  };
+
+  let mut covered: Vec<String> = Vec::new();
+
+  for case in switch.get_cases().iter() {
+    for value in case.get_values().iter() {
+      if let Some(name) = get_variant_name(value, &enum_name) {
+        covered.push(name);
+      }
+    }
+  }
+
+  variants.into_iter()
+    .filter(|variant| !covered.contains(variant))
+    .map(|variant| format!("`{}->{}`", enum_name, variant))
+    .collect()
+}
+
+pub fn check(
+  switch: &Switch,
+  environment: &mut Environment,
+) -> Result<TTypes, Error> {
+  let value_type: TTypes = check_expression(&switch.get_value(), environment)?;
+
+  let mut data_type: Option<TTypes> = None;
+  let mut literals: Vec<String> = Vec::new();
+
+  for case in switch.get_cases().iter() {
+    for value in case.get_values().iter() {
+      match check_expression(value, environment) {
+        Ok(token) => {
+          if !equal_types(value_type.get_type(), token.get_type()) && token.get_value() != "any" {
+            return Err(Error::from_token(
+              format!("`{}` not satisfied the `{}` data type.", token.get_value(), value_type.get_value()),
+              value.token(),
+            ).with_code(Codes::NOTSATISFIED));
+          }
+        },
+        Err(error) => {
+          return Err(error);
+        },
+      }
+
+      // Check if the case literal is duplicated.
+      if let Some(literal) = get_literal_value(value) {
+        if literals.contains(&literal) {
+          environment.add_warning(Error::from_token(
+            format!("duplicate case `{}`, it will never be reached.", value.string()),
+            value.token(),
+          ).with_code(Codes::UNREACHABLECASE));
+
+          continue;
+        }
+
+        literals.push(literal);
+      }
+    }
+
+    match check_statement(&case.get_consequence(), &mut scope_environment(environment, &[])) {
+      Ok(token) => {
+        // A case without returns does not change the switch type.
+        if token.get_type() == Types::VOID {
+          continue;
+        }
+
+        if data_type.clone().is_some() {
+          if !equal_types(data_type.clone().unwrap().get_type(), token.get_type()) && token.get_value() != "any" {
+            return Err(Error::from_token(
+              format!("`{}` not satisfied the `{}` data type.", token.get_value(), data_type.unwrap().get_value()),
+              token.get_token(),
+            ).with_code(Codes::NOTSATISFIED));
+          }
+
+          continue;
+        }
+
+        data_type = Some(token);
+      },
+      Err(error) => {
+        return Err(error);
+      },
+    }
+  }
+
+  if let Some(default) = switch.get_default() {
+    match check_statement(&default, &mut scope_environment(environment, &[])) {
+      // A default without returns does not change the switch type.
+      Ok(token) if token.get_type() == Types::VOID => {},
+      Ok(token) => {
+        if let Some(data_type) = data_type.clone() {
+          if !equal_types(data_type.get_type(), token.get_type()) && token.get_value() != "any" {
+            return Err(Error::from_token(
+              format!("`{}` not satisfied the `{}` data type.", token.get_value(), data_type.get_value()),
+              token.get_token(),
+            ).with_code(Codes::NOTSATISFIED));
+          }
+        } else {
+          data_type = Some(token);
+        }
+      },
+      Err(error) => {
+        return Err(error);
+      },
+    }
+  }
+
+  // Check if the cases cover all the enum variants.
+  let missing_variants: Vec<String> = get_missing_variants(switch, &value_type, environment);
+
+  if !missing_variants.is_empty() {
+    environment.add_warning(Error::from_token(
+      format!("{} not covered by the switch.", missing_variants.join(", ")),
+      switch.get_value().token(),
+    )
+      .with_code(Codes::NONEXHAUSTIVE)
+      .with_help(String::from("add a case for each variant or a `default`.")));
+  }
+
+  match data_type {
+    Some(data_type) => Ok(data_type),
+    None => Ok(TTypes::new_type(Types::VOID, String::from("void"), switch.get_token())),
+  }
+}
@@ -0,0 +1,48 @@
+use crate::{
+  Environment,
+  typechecker::{
+    check_expression,
+    equal_types,
+    get_identifier_ttype,
+    TTypes,
+  },
+};
+
+use sflyn_parser::{
+  Error,
+  Statement,
+  Throw,
+  tokens::Types,
+};
+
+/// Get the data type of the throw, it never returns so it satisfies any data type.
+fn get_throw_type(throw: &Throw) -> TTypes {
+  TTypes::new_type(Types::VOID, String::from("any"), throw.get_token())
+}
+
+pub fn check(
+  throw: &Throw,
+  environment: &mut Environment,
+) -> Result<TTypes, Error> {
+  match check_expression(&throw.get_value(), environment) {
+    Ok(token) => {
+      // Do not report more errors for a failed value.
+      if token.is_error() || token.get_type() == Types::STRING {
+        return Ok(get_throw_type(throw));
+      }
+
+      // Check if the value is an `Error`, like the catch error.
+      if let Some(error_type) = get_identifier_ttype(&String::from("Error"), environment) {
+        if equal_types(error_type.get_type(), token.get_type()) {
+          return Ok(get_throw_type(throw));
+        }
+      }
+
+      Err(Error::from_token(
+        format!("`{}` is not a `string` or an `Error`.", token.get_value()),
+        throw.get_value().token(),
+      ))
+    },
+    Err(error) => Err(error),
+  }
+}
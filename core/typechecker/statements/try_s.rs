@@ -0,0 +1,107 @@
+use crate::{
+  Environment,
+  Store,
+  typechecker::{
+    check_statement,
+    equal_types,
+    get_identifier_ttype,
+    TTypes,
+    union_ttypes,
+  },
+};
+
+use sflyn_parser::{
+  Codes,
+  Error,
+  Statement,
+  Try,
+  tokens::Types,
+};
+
+pub fn check(
+  try_s: &Try,
+  environment: &mut Environment,
+) -> Result<TTypes, Error> {
+  let mut data_type: Option<TTypes> = None;
+
+  // Create a new closed environment for the body.
+  let mut body_environment: Environment = environment.clone();
+
+  body_environment.store = Store::from_store(environment.store.clone());
+
+  let mut types: Vec<TTypes> = Vec::new();
+
+  match check_statement(&try_s.get_body(), &mut body_environment) {
+    Ok(token) => {
+      types.push(token);
+    },
+    Err(error) => {
+      return Err(error);
+    },
+  }
+
+  if let (Some(name), Some(catch)) = (try_s.get_catch_name(), try_s.get_catch()) {
+    // The catch error is an `Error` from the builtins.
+    let error_type = match get_identifier_ttype(&String::from("Error"), environment) {
+      Some(error_type) => error_type,
+      None => {
+        return Err(Error::from_token(
+          String::from("`Error` identifier not found."),
+          name,
+        ).with_code(Codes::IDENTIFIERNOTFOUND));
+      },
+    };
+
+    let mut catch_environment: Environment = environment.clone();
+
+    catch_environment.store = Store::from_store(environment.store.clone());
+    catch_environment.store.set_type(name.value, error_type);
+
+    match check_statement(&catch, &mut catch_environment) {
+      Ok(token) => {
+        types.push(token);
+      },
+      Err(error) => {
+        return Err(error);
+      },
+    }
+  }
+
+  if let Some(finally) = try_s.get_finally() {
+    let mut finally_environment: Environment = environment.clone();
+
+    finally_environment.store = Store::from_store(environment.store.clone());
+
+    match check_statement(&finally, &mut finally_environment) {
+      Ok(token) => {
+        types.push(token);
+      },
+      Err(error) => {
+        return Err(error);
+      },
+    }
+  }
+
+  for token in types {
+    // A block without returns does not change the try type.
+    if token.get_type() == Types::VOID {
+      continue;
+    }
+
+    data_type = match data_type {
+      Some(current) => {
+        if equal_types(current.get_type(), token.get_type()) || token.get_value() == "any" {
+          Some(current)
+        } else {
+          Some(union_ttypes(current, token))
+        }
+      },
+      None => Some(token),
+    };
+  }
+
+  match data_type {
+    Some(data_type) => Ok(data_type),
+    None => Ok(TTypes::new_type(Types::VOID, String::from("void"), try_s.get_token())),
+  }
+}
@@ -2,12 +2,15 @@ use crate::{
   Environment,
   typechecker::{
     check_expression,
-    equal_types,
+    equal_ttypes,
+    get_identifier_ttype,
+    satisfies_identifier_ttype,
     TTypes,
   },
 };
 
 use sflyn_parser::{
+  Codes,
   Error,
   Variable,
   Statement,
@@ -18,12 +21,12 @@ pub fn check(
   variable: &Variable,
   environment: &mut Environment,
 ) -> Result<TTypes, Error> {
-  // Check if the variable name is already in use.
-  if environment.store.get_type(&variable.get_name().value).is_some() {
+  // Check if the variable name is already in use in the current scope, the inner scopes can shadow it.
+  if environment.store.has_own_type(&variable.get_name().value) {
     return Err(Error::from_token(
       format!("`{}` is already in use.", variable.get_name().value),
       variable.get_name(),
-    ));
+    ).with_code(Codes::ALREADYINUSE));
   }
 
   let data_type = variable.get_type();
@@ -32,22 +35,63 @@ pub fn check(
     match check_expression(&value, environment) {
       Ok(token) => {
         if variable.get_token().token.expect_keyword(&Keywords::CONST) {
-          environment.store.set_const(variable.get_name().value);
+          environment.store.set_const(variable.get_name().value, variable.get_name());
         }
 
-        if data_type.value == "any" {
+        // Do not report more errors for a failed binding.
+        if data_type.value == "any" || token.is_error() {
           environment.store.set_type(variable.get_name().value, token.clone());
           return Ok(token);
+        }
+        // Check if the data type is an interface or a class.
+        else if data_type.token.is_identifier() {
+          match get_identifier_ttype(&data_type.value, environment) {
+            Some(ttype) => {
+              if satisfies_identifier_ttype(&ttype, &token) {
+                // Keep the interface members modifiers.
+                let variable_type = if ttype.is_interface() { ttype } else { token.clone() };
+
+                environment.store.set_type(variable.get_name().value, variable_type);
+                return Ok(token);
+              }
+
+              return Err(Error::from_token(
+                format!("`{}` not satisfied the `{}` data type.", token.get_value(), data_type.value),
+                value.token(),
+              )
+                .with_code(Codes::NOTSATISFIED)
+                .with_label(String::from("expected because of this data type."), data_type));
+            },
+            None => {
+              return Err(Error::from_token(
+                format!("`{}` identifier not found.", data_type.value),
+                data_type,
+              ).with_code(Codes::IDENTIFIERNOTFOUND));
+            },
+          }
         } else if let Some(ttype) = data_type.token.get_type() {
-          if equal_types(ttype, token.get_type()) || token.get_value() == "any" {
-            environment.store.set_type(variable.get_name().value, token.clone());
+          if equal_ttypes(ttype.clone(), &token) || token.get_value() == "any" {
+            // Keep the union data type, so the variable can change between its types.
+            let variable_type = if ttype.get_union().is_some() {
+              TTypes::new_type(ttype, data_type.value, variable.get_name())
+            }
+            // Keep the array data type, so an empty array has the elements data type.
+            else if ttype.get_array().is_some() {
+              TTypes::new_array(ttype, data_type.value, variable.get_name())
+            } else {
+              token.clone()
+            };
+
+            environment.store.set_type(variable.get_name().value, variable_type);
             return Ok(token);
           }
 
           return Err(Error::from_token(
             format!("`{}` not satisfied the `{}` data type.", token.get_value(), data_type.value),
             value.token(),
-          ));
+          )
+            .with_code(Codes::NOTSATISFIED)
+            .with_label(String::from("expected because of this data type."), data_type));
         }
       },
       Err(error) => {
@@ -0,0 +1,44 @@
+use crate::{
+  Environment,
+  Store,
+  typechecker::{
+    check_expression,
+    check_statement,
+    TTypes,
+  },
+};
+
+use sflyn_parser::{
+  Error,
+  tokens::Types,
+  While,
+};
+
+pub fn check(
+  while_s: &While,
+  environment: &mut Environment,
+) -> Result<TTypes, Error> {
+  // Check the condition expression.
+  match check_expression(&while_s.get_condition(), environment) {
+    Ok(token) => {
+      // The failed bindings are not reported again.
+      if !token.is_error() && token.get_type() != Types::BOOLEAN {
+        return Err(Error::from_token(
+          String::from("the condition is not a `boolean`."),
+          while_s.get_condition().token(),
+        ));
+      }
+    },
+    Err(error) => {
+      return Err(error);
+    },
+  }
+
+  // Create a new closed environment.
+  let mut while_environment: Environment = environment.clone();
+
+  while_environment.store = Store::from_store(environment.store.clone());
+
+  // Get the ttypes from the while body.
+  check_statement(&while_s.get_body(), &mut while_environment)
+}
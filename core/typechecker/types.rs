@@ -1,11 +1,17 @@
 use sflyn_parser::{
+  Argument,
+  Expression,
   Expressions,
   tokens::{
+    Keywords,
     Token,
+    Tokens,
     Types,
   },
 };
 
+use super::replace_generics;
+
 use std::collections::HashMap;
 
 #[derive(Debug, Clone, PartialEq)]
@@ -19,6 +25,14 @@ pub enum TType {
 
   FORIN,
   FOROF,
+
+  CLASS,
+  INSTANCE,
+
+  ENUM,
+  ENUMVARIANT,
+
+  ERROR,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -31,6 +45,7 @@ pub struct TTypes {
   names: Vec<String>,
   arguments: Vec<Box<Expressions>>,
   methods: HashMap<String, TTypes>,
+  modifiers: HashMap<String, Vec<Keywords>>,
 }
 
 impl TTypes {
@@ -42,6 +57,7 @@ impl TTypes {
     names: Vec<String>,
     arguments: Vec<Box<Expressions>>,
     methods: HashMap<String, TTypes>,
+    modifiers: HashMap<String, Vec<Keywords>>,
   ) -> Self {
     Self {
       ttype,
@@ -51,6 +67,7 @@ impl TTypes {
       names,
       arguments,
       methods,
+      modifiers,
     }
   }
 
@@ -67,6 +84,7 @@ impl TTypes {
       Vec::new(),
       Vec::new(),
       HashMap::new(),
+      HashMap::new(),
     )
   }
 
@@ -74,16 +92,19 @@ impl TTypes {
     data_type: Types,
     type_value: String,
     token: Token,
+    names: Vec<String>,
     methods: HashMap<String, TTypes>,
+    modifiers: HashMap<String, Vec<Keywords>>,
   ) -> Self {
     Self::new(
       TType::INTERFACE,
       data_type,
       type_value,
       token,
-      Vec::new(),
+      names,
       Vec::new(),
       methods,
+      modifiers,
     )
   } 
 
@@ -91,6 +112,7 @@ impl TTypes {
     data_type: Types,
     type_value: String,
     token: Token,
+    names: Vec<String>,
     arguments: Vec<Box<Expressions>>,
   ) -> Self {
     Self::new(
@@ -98,9 +120,10 @@ impl TTypes {
       data_type,
       type_value,
       token,
-      Vec::new(),
+      names,
       arguments,
       HashMap::new(),
+      HashMap::new(),
     )
   }
 
@@ -118,6 +141,7 @@ impl TTypes {
       Vec::new(),
       Vec::new(),
       methods,
+      HashMap::new(),
     )
   }
 
@@ -134,6 +158,7 @@ impl TTypes {
       Vec::new(),
       Vec::new(),
       HashMap::new(),
+      HashMap::new(),
     )
   }
 
@@ -151,6 +176,7 @@ impl TTypes {
       [name].to_vec(),
       Vec::new(),
       HashMap::new(),
+      HashMap::new(),
     )
   }
 
@@ -169,9 +195,195 @@ impl TTypes {
       names,
       Vec::new(),
       methods,
+      HashMap::new(),
+    )
+  }
+
+  pub fn new_class(
+    data_type: Types,
+    type_value: String,
+    token: Token,
+    arguments: Vec<Box<Expressions>>,
+    methods: HashMap<String, TTypes>,
+    modifiers: HashMap<String, Vec<Keywords>>,
+  ) -> Self {
+    Self::new(
+      TType::CLASS,
+      data_type,
+      type_value,
+      token,
+      Vec::new(),
+      arguments,
+      methods,
+      modifiers,
     )
   }
 
+  pub fn new_instance(
+    data_type: Types,
+    type_value: String,
+    token: Token,
+    methods: HashMap<String, TTypes>,
+    modifiers: HashMap<String, Vec<Keywords>>,
+  ) -> Self {
+    Self::new(
+      TType::INSTANCE,
+      data_type,
+      type_value,
+      token,
+      Vec::new(),
+      Vec::new(),
+      methods,
+      modifiers,
+    )
+  }
+
+  pub fn new_enum(
+    data_type: Types,
+    type_value: String,
+    token: Token,
+    names: Vec<String>,
+    methods: HashMap<String, TTypes>,
+  ) -> Self {
+    Self::new(
+      TType::ENUM,
+      data_type,
+      type_value,
+      token,
+      names,
+      Vec::new(),
+      methods,
+      HashMap::new(),
+    )
+  }
+
+  pub fn new_enum_variant(
+    data_type: Types,
+    type_value: String,
+    token: Token,
+    methods: HashMap<String, TTypes>,
+  ) -> Self {
+    Self::new(
+      TType::ENUMVARIANT,
+      data_type,
+      type_value,
+      token,
+      Vec::new(),
+      Vec::new(),
+      methods,
+      HashMap::new(),
+    )
+  }
+
+  /// Get the data type of a failed binding, it is accepted everywhere to avoid follow-up errors.
+  pub fn new_error(token: Token) -> Self {
+    Self::new(
+      TType::ERROR,
+      Types::NULL,
+      String::from("any"),
+      token,
+      Vec::new(),
+      Vec::new(),
+      HashMap::new(),
+      HashMap::new(),
+    )
+  }
+
+  /// Get the variant type of an enum type.
+  pub fn to_enum_variant(&self, token: Token) -> Self {
+    let mut methods: HashMap<String, TTypes> = HashMap::new();
+
+    if let Some(variant) = self.methods.values().next() {
+      methods = variant.get_methods();
+    }
+
+    Self::new_enum_variant(
+      self.get_type(),
+      self.get_value(),
+      token,
+      methods,
+    )
+  }
+
+  /// Get the instance type of a class type.
+  pub fn to_instance(&self, token: Token) -> Self {
+    Self::new_instance(
+      self.get_type(),
+      self.get_value(),
+      token,
+      self.get_methods(),
+      self.modifiers.clone(),
+    )
+  }
+
+  /// Get a copy of the data type with the generic names replaced by data types.
+  pub fn replace_generics(&self, generics: &HashMap<String, String>) -> Self {
+    let type_value: String = replace_generics(&self.type_value, generics);
+    let mut data_type: Types = self.data_type.clone();
+
+    if type_value != self.type_value {
+      if let Some(new_data_type) = Token::from_value(&type_value, 0, 0).token.get_type() {
+        data_type = new_data_type;
+      }
+    }
+
+    let mut names: Vec<String> = Vec::new();
+
+    for name in self.names.iter() {
+      if !generics.contains_key(name) {
+        names.push(name.clone());
+      }
+    }
+
+    let mut arguments: Vec<Box<Expressions>> = Vec::new();
+
+    for argument in self.arguments.iter() {
+      match argument.get_argument() {
+        Some(argument) => {
+          let mut argument_type: Token = argument.get_type();
+          let argument_value: String = replace_generics(&argument_type.value, generics);
+
+          if argument_value != argument_type.value {
+            let mut new_type = Token::from_value(&argument_value, argument_type.line, argument_type.position);
+
+            // The data types that are not primitives are identifiers (like interfaces).
+            if new_type.token.is_illegal() {
+              new_type.token = Box::new(Tokens::IDENTIFIER);
+            }
+
+            argument_type = new_type;
+          }
+
+          arguments.push(Argument::new_box_full(argument.get_token(), argument_type, argument.get_value()));
+        },
+        None => {
+          arguments.push(argument.clone());
+        },
+      }
+    }
+
+    let mut methods: HashMap<String, TTypes> = HashMap::new();
+
+    for (key, value) in self.methods.iter() {
+      methods.insert(key.clone(), value.replace_generics(generics));
+    }
+
+    Self::new(
+      self.ttype.clone(),
+      data_type,
+      type_value,
+      self.token.clone(),
+      names,
+      arguments,
+      methods,
+      self.modifiers.clone(),
+    )
+  }
+
+  pub fn is_error(&self) -> bool {
+    self.ttype == TType::ERROR
+  }
+
   pub fn is_interface(&self) -> bool {
     self.ttype == TType::INTERFACE
   }
@@ -196,6 +408,22 @@ impl TTypes {
     self.ttype == TType::FOROF
   }
 
+  pub fn is_class(&self) -> bool {
+    self.ttype == TType::CLASS
+  }
+
+  pub fn is_instance(&self) -> bool {
+    self.ttype == TType::INSTANCE
+  }
+
+  pub fn is_enum(&self) -> bool {
+    self.ttype == TType::ENUM
+  }
+
+  pub fn is_enum_variant(&self) -> bool {
+    self.ttype == TType::ENUMVARIANT
+  }
+
   pub fn get_type(&self) -> Types {
     self.data_type.clone()
   }
@@ -219,4 +447,17 @@ impl TTypes {
   pub fn get_methods(&self) -> HashMap<String, TTypes> {
     self.methods.clone()
   }
+
+  /// Get the modifiers of a member.
+  pub fn get_modifiers(&self, name: &String) -> Vec<Keywords> {
+    match self.modifiers.get(name) {
+      Some(modifiers) => modifiers.clone(),
+      None => Vec::new(),
+    }
+  }
+
+  /// Check if a member has a specific modifier.
+  pub fn has_modifier(&self, name: &String, modifier: Keywords) -> bool {
+    self.get_modifiers(name).contains(&modifier)
+  }
 }
@@ -1,28 +1,75 @@
-use crate::typechecker::TTypes;
+use crate::{
+  Environment,
+  Store,
+  typechecker::{
+    check_expression,
+    TTypes,
+  },
+};
 
-use sflyn_parser::tokens::{
-  Token,
-  Types,
+use sflyn_parser::{
+  Argument,
+  Codes,
+  Error,
+  Expression,
+  Expressions,
+  tokens::{
+    Keywords,
+    Signs,
+    Token,
+    Tokens,
+    Types,
+    Union,
+  },
 };
 
 use std::collections::HashMap;
 
+/// Check if the second data type can be used as the first data type.
 pub fn equal_types(one: Types, two: Types) -> bool {
+  // Check if the first type is an union.
+  if let Some(one_union) = one.get_union() {
+    // Every type of the second union must be in the first union.
+    if let Some(two_union) = two.get_union() {
+      for two_type in two_union.get_types().iter() {
+        if let Some(two_type) = two_type.token.get_type() {
+          if equal_types(one.clone(), two_type) {
+            continue;
+          }
+        }
+        // The classes and the enums are compared by their names.
+        else if one_union.get_types().iter().any(|one_type| equal_tokens(one_type.clone(), two_type.clone())) {
+          continue;
+        }
+
+        return false;
+      }
+
+      return true;
+    }
+
+    for one_type in one_union.get_types().iter() {
+      if let Some(one_type) = one_type.token.get_type() {
+        if equal_types(one_type, two.clone()) {
+          return true;
+        }
+      }
+    }
+
+    return false;
+  }
   // Check if both types are functions.
   if one.get_function().is_some() && two.get_function().is_some() {
     let one_function = one.get_function().unwrap();
     let two_function = two.get_function().unwrap();
 
+    // Compare the arguments by position, their names can be different.
     if one_function.get_arguments().len() == two_function.get_arguments().len() &&
       equal_tokens(one_function.get_type(), two_function.get_type()) {
-      for (key, value) in one_function.get_arguments().iter() {
-        if let Some(value_token) = two_function.get_arguments().get(key) {
-          if equal_tokens(value.clone(), value_token.clone()) {
-            continue;
-          }
+      for ((_, value), (_, value_token)) in one_function.get_arguments().iter().zip(two_function.get_arguments().iter()) {
+        if !equal_tokens(value.clone(), value_token.clone()) {
+          return false;
         }
-
-        return false;
       }
 
       return true;
@@ -33,19 +80,30 @@ pub fn equal_types(one: Types, two: Types) -> bool {
     let one_hashmap = one.get_hashmap().unwrap();
     let two_hashmap = two.get_hashmap().unwrap();
 
-    if one_hashmap.get_items().len() == two_hashmap.get_items().len() {
-      for (key, value) in one_hashmap.get_items().iter() {
-        if let Some(value_token) = two_hashmap.get_items().get(key) {
-          if equal_tokens(value.clone(), value_token.clone()) {
-            continue;
-          }
-        }
-
+    // Every key of the second hashmap must be in the first hashmap.
+    for key in two_hashmap.get_items().keys() {
+      if !one_hashmap.get_items().contains_key(key) {
         return false;
       }
+    }
 
-      return true;
+    for (key, value) in one_hashmap.get_items().iter() {
+      if let Some(value_token) = two_hashmap.get_items().get(key) {
+        if equal_tokens(value.clone(), value_token.clone()) {
+          continue;
+        }
+      }
+      // The keys that can be null are optional.
+      else if let Some(Types::UNION(union)) = value.token.get_type() {
+        if union.get_types().iter().any(|union_type| union_type.token.expect_type(&Types::NULL)) {
+          continue;
+        }
+      }
+
+      return false;
     }
+
+    return true;
   }
   // Check if both types are arrays.
   else if one.get_array().is_some() && two.get_array().is_some() {
@@ -63,9 +121,27 @@ pub fn equal_tokens(one: Token, two: Token) -> bool {
     return equal_types(one.token.get_type().unwrap(), two.token.get_type().unwrap());
   }
 
+  // The classes and the enums are compared by their names, not by their positions.
+  if one.token.get_type().is_none() && two.token.get_type().is_none() {
+    return one.value == two.value;
+  }
+
   one == two
 }
 
+/// Check if the value data type can be used as the data type, the class instances and the enum
+/// variants are compared by their names inside the unions.
+pub fn equal_ttypes(one: Types, two: &TTypes) -> bool {
+  if two.is_instance() || two.is_enum_variant() {
+    if let Some(union) = one.get_union() {
+      return union.get_types().iter()
+        .any(|union_type| union_type.token.get_type().is_none() && union_type.value == two.get_value());
+    }
+  }
+
+  equal_types(one, two.get_type())
+}
+
 pub fn get_ttypes_from_token(
   token: Token,
   token_to_ttype: Token,
@@ -102,8 +178,23 @@ pub fn get_ttypes_from_token(
     }
     // Check if is a function.
     else if let Some(function) = token_type.get_function() {
-      println!("Function TType: {:?}\n", function);
-      return None;
+      let mut arguments: Vec<Box<Expressions>> = Vec::new();
+
+      for (key, value) in function.get_arguments().iter() {
+        arguments.push(Argument::new_box_full(
+          Token::new(Box::new(Tokens::IDENTIFIER), key.clone(), value.line, value.position),
+          value.clone(),
+          None,
+        ));
+      }
+
+      return Some(TTypes::new_function(
+        token_type,
+        token.value,
+        token_to_ttype,
+        Vec::new(),
+        arguments,
+      ));
     }
 
     return Some(TTypes::new_type(
@@ -115,3 +206,400 @@ pub fn get_ttypes_from_token(
 
   None
 }
+
+/// Get the union of two data types.
+pub fn union_ttypes(one: TTypes, two: TTypes) -> TTypes {
+  let union = Union::new(vec![
+    Token::new(Box::new(Tokens::TYPE(one.get_type())), one.get_value(), 0, 0),
+    Token::new(Box::new(Tokens::TYPE(two.get_type())), two.get_value(), 0, 0),
+  ]);
+  let value = union.get_value();
+
+  TTypes::new_type(Types::UNION(union), value, two.get_token())
+}
+
+/// Check if the data type is an union with `null`.
+pub fn is_nullable(ttype: &TTypes) -> bool {
+  if let Some(union) = ttype.get_type().get_union() {
+    for union_type in union.get_types().iter() {
+      if union_type.token.expect_type(&Types::NULL) {
+        return true;
+      }
+    }
+  }
+
+  false
+}
+
+/// Split an union data type in the types that satisfy the data type and the rest of them.
+fn split_union(
+  variable_type: &TTypes,
+  data_type: Types,
+  environment: &Environment,
+) -> Option<(Option<TTypes>, Option<TTypes>)> {
+  let union: Union = variable_type.get_type().get_union()?;
+
+  let mut matched: Option<TTypes> = None;
+  let mut rest: Vec<Token> = Vec::new();
+
+  for union_type in union.get_types().iter() {
+    if let Some(union_data_type) = union_type.token.get_type() {
+      if equal_types(data_type.clone(), union_data_type) {
+        matched = get_ttypes_from_token(union_type.clone(), variable_type.get_token());
+        continue;
+      }
+    }
+
+    rest.push(union_type.clone());
+  }
+
+  let rest_type: Option<TTypes> = match rest.len() {
+    0 => None,
+    // The rest can be the instances of a class or the variants of an enum.
+    1 => get_ttypes_from_token(rest[0].clone(), variable_type.get_token()).or_else(|| {
+      get_identifier_ttype(&rest[0].value, environment)
+        .and_then(|ttype| get_identifier_value_ttype(&ttype, variable_type.get_token()))
+    }),
+    _ => {
+      let union = Union::new(rest);
+      let value = union.get_value();
+
+      Some(TTypes::new_type(Types::UNION(union), value, variable_type.get_token()))
+    },
+  };
+
+  Some((matched, rest_type))
+}
+
+/// Get the narrowed types of an `identifier is type`, `identifier == null` or `identifier != null`
+/// condition when the identifier is an union, the conditions can be joined with `&&`.
+/// Returns the identifier name, the type when the condition is true and the type when it is false.
+pub fn get_narrowed_types(
+  condition: &Expressions,
+  environment: &Environment,
+) -> Option<(String, TTypes, Option<TTypes>)> {
+  let infix = condition.get_infix()?;
+
+  // Check if the condition is an `and`, the rest types are unknown.
+  if infix.get_token().token.expect_sign(&Signs::AND) {
+    return match get_narrowed_types(&infix.get_left(), environment) {
+      Some((name, narrowed, _)) => Some((name, narrowed, None)),
+      None => {
+        let right = infix.get_right()?;
+        let (name, narrowed, _) = get_narrowed_types(&right, environment)?;
+
+        Some((name, narrowed, None))
+      },
+    };
+  }
+
+  let name: String = infix.get_left().get_identifier()?.get_value();
+  let variable_type: TTypes = environment.store.get_type(&name)?;
+
+  // Check if the condition is an `identifier is type`.
+  if infix.is_type() {
+    let right_type: Types = infix.get_right_type()?.token.get_type()?;
+    let (narrowed, rest) = split_union(&variable_type, right_type, environment)?;
+
+    return Some((name, narrowed?, rest));
+  }
+
+  // Check if the condition is an `identifier == null` or an `identifier != null`.
+  if infix.get_right()?.get_null().is_some() {
+    let (null_type, rest) = split_union(&variable_type, Types::NULL, environment)?;
+
+    if infix.get_token().token.expect_sign(&Signs::EQUAL) {
+      return Some((name, null_type?, rest));
+    } else if infix.get_token().token.expect_sign(&Signs::NOTEQUAL) {
+      return Some((name, rest?, null_type));
+    }
+  }
+
+  None
+}
+
+/// Get a new environment with the narrowed types.
+pub fn narrow_environment(
+  environment: &Environment,
+  narrowed_types: &[(String, TTypes)],
+) -> Option<Environment> {
+  if narrowed_types.is_empty() {
+    return None;
+  }
+
+  Some(scope_environment(environment, narrowed_types))
+}
+
+/// Get a new environment with an inner scope for a block, with the narrowed types.
+pub fn scope_environment(
+  environment: &Environment,
+  narrowed_types: &[(String, TTypes)],
+) -> Environment {
+  let mut scope_environment: Environment = environment.clone();
+
+  scope_environment.store = Store::from_store(environment.store.clone());
+
+  for (name, ttype) in narrowed_types.iter() {
+    scope_environment.store.set_type(name.clone(), ttype.clone());
+  }
+
+  scope_environment
+}
+
+/// Replace the generic names of a data type value with other data type values.
+pub fn replace_generics(value: &str, generics: &HashMap<String, String>) -> String {
+  let mut new_value = String::new();
+  let mut word = String::new();
+
+  for character in value.chars().chain(std::iter::once(' ')) {
+    if character.is_alphanumeric() || character == '_' {
+      word.push(character);
+      continue;
+    }
+
+    if !word.is_empty() {
+      match generics.get(&word) {
+        Some(data_type) => new_value.push_str(data_type),
+        None => new_value.push_str(&word),
+      }
+
+      word = String::new();
+    }
+
+    new_value.push(character);
+  }
+
+  // Remove the space used to read the last word.
+  new_value.pop();
+
+  new_value
+}
+
+/// Infer the generic data types of a parameter data type from the argument data type.
+pub fn infer_generics(
+  parameter: Types,
+  argument: Types,
+  argument_value: String,
+  generics: &mut HashMap<String, String>,
+) {
+  match (parameter, argument) {
+    (Types::GENERIC(name), _) => {
+      generics.entry(name).or_insert(argument_value);
+    },
+    (Types::ARRAY(parameter_array), Types::ARRAY(argument_array)) => {
+      if let (Some(parameter_type), Some(argument_type)) = (
+        parameter_array.get_type().token.get_type(),
+        argument_array.get_type().token.get_type(),
+      ) {
+        infer_generics(parameter_type, argument_type, argument_array.get_type().value, generics);
+      }
+    },
+    (Types::UNION(parameter_union), argument) => {
+      // Use the argument for the generic when it does not satisfy the other union types.
+      for union_type in parameter_union.get_types().iter() {
+        if let Some(union_data_type) = union_type.token.get_type() {
+          if union_data_type.get_generic().is_none() && equal_types(union_data_type, argument.clone()) {
+            return;
+          }
+        }
+      }
+
+      for union_type in parameter_union.get_types().iter() {
+        if let Some(Types::GENERIC(name)) = union_type.token.get_type() {
+          generics.entry(name).or_insert(argument_value);
+          return;
+        }
+      }
+    },
+    (Types::FUNCTION(parameter_function), Types::FUNCTION(argument_function)) => {
+      if let (Some(parameter_type), Some(argument_type)) = (
+        parameter_function.get_type().token.get_type(),
+        argument_function.get_type().token.get_type(),
+      ) {
+        infer_generics(parameter_type, argument_type, argument_function.get_type().value, generics);
+      }
+
+      for ((_, value), (_, argument_value)) in parameter_function.get_arguments().iter().zip(argument_function.get_arguments().iter()) {
+        if let (Some(parameter_type), Some(argument_type)) = (value.token.get_type(), argument_value.token.get_type()) {
+          infer_generics(parameter_type, argument_type, argument_value.value.clone(), generics);
+        }
+      }
+    },
+    (Types::HASHMAP(parameter_hashmap), Types::HASHMAP(argument_hashmap)) => {
+      for (key, value) in parameter_hashmap.get_items().iter() {
+        if let Some(argument_value) = argument_hashmap.get_items().get(key) {
+          if let (Some(parameter_type), Some(argument_type)) = (value.token.get_type(), argument_value.token.get_type()) {
+            infer_generics(parameter_type, argument_type, argument_value.value.clone(), generics);
+          }
+        }
+      }
+    },
+    _ => {},
+  }
+}
+
+/// Get the error of a change to a const, the imported bindings are consts too.
+pub fn get_const_error(token: Token, declaration: Token, environment: &Environment) -> Error {
+  let error = Error::from_token(
+    format!("`{}` is a const.", token.value),
+    token.clone(),
+  ).with_code(Codes::CONSTASSIGNMENT);
+
+  if environment.store.is_import(&token.value) {
+    return error
+      .with_label(String::from("imported here."), declaration)
+      .with_help(String::from("the imported bindings can not be changed, copy the value to a `let` variable."));
+  }
+
+  error
+    .with_label(String::from("declared as a const here."), declaration)
+    .with_help(String::from("declare it with `let` to change its value."))
+}
+
+/// Get the standard library interface with the native members of the data type (`length`,
+/// `toString()`...).
+pub fn get_std_interface(ttype: &TTypes, environment: &Environment) -> Option<TTypes> {
+  let name = if ttype.is_enum_variant() {
+    "Enum"
+  } else if ttype.get_type() == Types::NULL {
+    "Null"
+  } else if ttype.get_type() == Types::STRING {
+    "String"
+  } else if ttype.get_type() == Types::NUMBER {
+    "Number"
+  } else if ttype.get_type() == Types::BOOLEAN {
+    "Boolean"
+  } else if ttype.get_type().get_array().is_some() {
+    "Array"
+  } else {
+    return None;
+  };
+
+  match environment.store.get_type(&name.to_string()) {
+    Some(data_type) if data_type.is_interface() => Some(data_type),
+    _ => None,
+  }
+}
+
+/// Check if the left expression of an assignment, a `++` or a `--` can be changed. The consts,
+/// the readonly fields (also by their names inside the methods) and the readonly members can not.
+pub fn check_assignable(left: &Expressions, environment: &mut Environment) -> Result<(), Error> {
+  let mut name = String::new();
+  let mut token = Token::new_empty();
+
+  if let Some(identifier) = left.get_identifier() {
+    name = identifier.get_value();
+    token = identifier.get_token();
+  } else if let Some(array_index) = left.get_array_index() {
+    name = array_index.get_token().value;
+    token = array_index.get_token();
+  }
+
+  // Check if the variable is a const.
+  if let Some(declaration) = environment.store.get_const(&name) {
+    return Err(get_const_error(token, declaration, environment));
+  }
+
+  // Check if the variable is a readonly field of the method class.
+  if let Some(declaration) = environment.store.get_readonly(&name) {
+    return Err(Error::from_token(
+      format!("`{}` is a readonly field.", name),
+      token,
+    ).with_label(String::from("declared as readonly here."), declaration));
+  }
+
+  // Check if the left expression is a readonly member.
+  if let Some(method) = left.get_infix() {
+    if method.is_method() && method.get_right().is_some() {
+      let member: Token = method.get_right().unwrap().token();
+      let owner_type: TTypes = check_expression(&method.get_left(), environment)?;
+
+      let readonly = owner_type.has_modifier(&member.value, Keywords::READONLY) ||
+        get_std_interface(&owner_type, environment)
+          .is_some_and(|data_type| data_type.has_modifier(&member.value, Keywords::READONLY));
+
+      if readonly {
+        return Err(Error::from_token(
+          format!("`{}` is a readonly field.", member.value),
+          member,
+        ));
+      }
+    }
+  }
+
+  Ok(())
+}
+
+/// Get the data type of an identifier data type, the generic interfaces are used with
+/// their generic arguments (`Box<number>`).
+pub fn get_identifier_ttype(value: &String, environment: &Environment) -> Option<TTypes> {
+  if !value.ends_with('>') {
+    return environment.store.get_type(value);
+  }
+
+  let start = value.find('<')?;
+  let ttype: TTypes = environment.store.get_type(&value[..start].to_string())?;
+
+  let mut arguments: Vec<String> = Vec::new();
+  let mut current = String::new();
+  let mut depth: usize = 0;
+
+  // Split the generic arguments by the `,` outside parentheses, brackets, braces and generics.
+  for character in value[start + 1..value.len() - 1].chars() {
+    match character {
+      '(' | '[' | '{' | '<' => depth += 1,
+      ')' | ']' | '}' | '>' => depth = depth.saturating_sub(1),
+      ',' if depth == 0 => {
+        arguments.push(current.trim().to_string());
+        current = String::new();
+        continue;
+      },
+      _ => {},
+    }
+
+    current.push(character);
+  }
+
+  arguments.push(current.trim().to_string());
+
+  if !ttype.is_interface() || ttype.get_names().len() != arguments.len() {
+    return None;
+  }
+
+  let mut generics: HashMap<String, String> = HashMap::new();
+
+  for (name, argument) in ttype.get_names().iter().zip(arguments) {
+    generics.insert(name.clone(), argument);
+  }
+
+  Some(ttype.replace_generics(&generics))
+}
+
+/// Check if the value data type satisfies the class, enum or interface of an identifier data type.
+pub fn satisfies_identifier_ttype(identifier_type: &TTypes, value_type: &TTypes) -> bool {
+  // The failed declarations are not reported again at their uses.
+  if identifier_type.is_error() {
+    true
+  } else if identifier_type.is_class() {
+    value_type.is_instance() && value_type.get_value() == identifier_type.get_value()
+  } else if identifier_type.is_enum() {
+    value_type.is_enum_variant() && value_type.get_value() == identifier_type.get_value()
+  } else if identifier_type.is_interface() {
+    equal_types(identifier_type.get_type(), value_type.get_type())
+  } else {
+    false
+  }
+}
+
+/// Get the data type of the values of a class, enum or interface identifier data type.
+pub fn get_identifier_value_ttype(identifier_type: &TTypes, token: Token) -> Option<TTypes> {
+  if identifier_type.is_class() {
+    Some(identifier_type.to_instance(token))
+  } else if identifier_type.is_enum() {
+    Some(identifier_type.to_enum_variant(token))
+  } else if identifier_type.is_interface() {
+    Some(identifier_type.clone())
+  } else {
+    None
+  }
+}
@@ -0,0 +1,72 @@
+mod bytecode;
+mod compiler;
+mod machine;
+
+pub use bytecode::*;
+pub use compiler::*;
+pub use machine::*;
+
+use sflyn_parser::{
+  Codes,
+  Error,
+  File,
+};
+
+use super::{
+  compiler::run_stdlibs,
+  Environment,
+  error::{
+    show_diagnostic,
+    show_error,
+    show_warning,
+  },
+};
+
+pub fn run(
+  file: File,
+  environment: &mut Environment,
+  with_stdlib: bool,
+) -> i32 {
+  // Evaluate stdlib.
+  if with_stdlib && run_stdlibs(environment) != 0 {
+    return 1;
+  }
+
+  environment.running_file = file.name.clone();
+
+  let function = match compile(&file) {
+    Ok(function) => function,
+    Err(token) => {
+      let error = Error::from_token(
+        format!("`{}` does not have bytecode yet.", token.value),
+        token,
+      );
+
+      // The `--vm` flag does not allow to use the tree-walking evaluator.
+      if environment.arguments.flag_vm {
+        show_error(file, error, Codes::RUNTIME, &environment.arguments.error_format);
+        return 1;
+      }
+
+      show_warning(
+        file.clone(),
+        error.with_help(String::from("the file runs on the tree-walking evaluator.")),
+        Codes::NOBYTECODE,
+        &environment.arguments.error_format,
+      );
+
+      return crate::compiler::run(file, environment, false);
+    },
+  };
+
+  let object = Machine::new(environment).run(function);
+
+  // Check if the object is an error.
+  if let Some(error) = object.get_error() {
+    // An uncaught error stops the program.
+    show_diagnostic(&file, &error.get_diagnostic(), &environment.arguments.error_format);
+    return 1;
+  }
+
+  0
+}
@@ -0,0 +1,141 @@
+use crate::compiler::Objects;
+
+use sflyn_parser::{
+  Import,
+  tokens::{
+    Signs,
+    Token,
+  },
+};
+
+use std::rc::Rc;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Instructions {
+  // Values
+  CONSTANT(usize),
+  NULL,
+  POP,
+  ARRAY(usize),
+  TEMPLATE(usize),
+  HASHMAP(Vec<String>),
+  OPTIONALS(Vec<String>),              // Missing members of the hashmap, added as null
+  ENUM(String, Vec<(String, bool)>),   // Name and variants, with if they have a value
+  CLASS(String, Vec<String>),          // Name and methods
+
+  // Variables
+  DEFINELOCAL(usize),
+  GETLOCAL(usize),
+  SETLOCAL(usize),
+  GETUPVALUE(usize),
+  SETUPVALUE(usize),
+  GETGLOBAL(usize),
+  SETGLOBAL(usize),
+  DEFINEGLOBAL(usize),
+  SETCONST(Token),
+
+  // Operators
+  BINARY(Signs),
+  NOT,
+  NEGATE,
+  IS(Token),
+  AND(usize),
+  OR(usize),
+
+  // Arrays and members
+  INDEX,
+  SETINDEX,
+  MEMBER(usize),
+  SETMEMBER(usize),
+  METHOD(usize, usize),
+
+  // Jumps
+  JUMP(usize),
+  JUMPIFFALSE(usize),
+  JUMPIFARGUMENT(usize, usize),
+  ITERATOR(bool),
+  NEXT(usize, usize, usize),
+
+  // Functions
+  CLOSURE(usize, Vec<Capture>),
+  CALL(usize),
+  RETURN,
+
+  // Modules
+  IMPORT(Import),
+
+  // Exceptions
+  THROW,
+  CATCH,
+  PUSHHANDLER(usize),
+  POPHANDLER,
+}
+
+/// A variable captured by a closure, from the enclosing function locals or upvalues.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Capture {
+  LOCAL(usize),
+  UPVALUE(usize),
+}
+
+/// The compiled code of a function.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Chunk {
+  pub code: Vec<Instructions>,
+  pub tokens: Vec<Token>,          // Token of each instruction, for the errors
+  pub constants: Vec<Box<Objects>>,
+  pub functions: Vec<Rc<Function>>,
+}
+
+impl Chunk {
+  pub fn new() -> Chunk {
+    Chunk {
+      code: Vec::new(),
+      tokens: Vec::new(),
+      constants: Vec::new(),
+      functions: Vec::new(),
+    }
+  }
+
+  /// Add an instruction and get its position.
+  pub fn emit(&mut self, instruction: Instructions, token: Token) -> usize {
+    self.code.push(instruction);
+    self.tokens.push(token);
+
+    self.code.len() - 1
+  }
+
+  /// Add a constant and get its index, reusing the same constant.
+  pub fn add_constant(&mut self, object: Box<Objects>) -> usize {
+    match self.constants.iter().position(|constant| constant == &object) {
+      Some(index) => index,
+      None => {
+        self.constants.push(object);
+        self.constants.len() - 1
+      },
+    }
+  }
+
+  /// Set the target of a jump instruction.
+  pub fn patch(&mut self, position: usize, target: usize) {
+    self.code[position] = match self.code[position].clone() {
+      Instructions::JUMP(_) => Instructions::JUMP(target),
+      Instructions::JUMPIFFALSE(_) => Instructions::JUMPIFFALSE(target),
+      Instructions::JUMPIFARGUMENT(index, _) => Instructions::JUMPIFARGUMENT(index, target),
+      Instructions::NEXT(collection, index, _) => Instructions::NEXT(collection, index, target),
+      Instructions::AND(_) => Instructions::AND(target),
+      Instructions::OR(_) => Instructions::OR(target),
+      Instructions::PUSHHANDLER(_) => Instructions::PUSHHANDLER(target),
+      instruction => instruction,
+    };
+  }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Function {
+  pub name: String,
+  pub arguments: usize,
+  pub locals: usize,
+  pub chunk: Chunk,
+  pub file: String,
+}
@@ -0,0 +1,1498 @@
+use crate::compiler::{
+  Boolean,
+  Number,
+  Objects,
+  StringO,
+};
+
+use sflyn_parser::{
+  Argument,
+  Class,
+  Expression,
+  Expressions,
+  File,
+  ForCondition,
+  Infix,
+  Statement,
+  Statements,
+  SwitchCase,
+  Try,
+  tokens::{
+    Keywords,
+    Signs,
+    Token,
+  },
+};
+
+use std::{
+  collections::HashMap,
+  rc::Rc,
+};
+
+use super::{
+  Capture,
+  Chunk,
+  Function,
+  Instructions,
+};
+
+/// A loop or a switch, with the jumps to patch when it ends.
+struct LoopState {
+  is_switch: bool,
+  tries: usize,
+  breaks: Vec<usize>,
+  continues: Vec<usize>,
+}
+
+/// A running try, with the finally to run when a jump leaves it.
+#[derive(Clone)]
+struct TryState {
+  has_handler: bool,
+  finally: Option<Box<Statements>>,
+}
+
+struct FunctionState {
+  name: String,
+  arguments: usize,
+  locals: usize,
+  chunk: Chunk,
+  scopes: Vec<Vec<(String, usize)>>,
+  consts: HashMap<usize, Token>,
+  upvalues: Vec<Capture>,
+  loops: Vec<LoopState>,
+  tries: Vec<TryState>,
+  fields: HashMap<String, Option<Token>>,    // Class fields of a method, with the const declarations
+}
+
+impl FunctionState {
+  fn new(name: String) -> FunctionState {
+    FunctionState {
+      name,
+      arguments: 0,
+      locals: 0,
+      chunk: Chunk::new(),
+      scopes: vec![Vec::new()],
+      consts: HashMap::new(),
+      upvalues: Vec::new(),
+      loops: Vec::new(),
+      tries: Vec::new(),
+      fields: HashMap::new(),
+    }
+  }
+}
+
+pub struct Compiler {
+  file: String,
+  functions: Vec<FunctionState>,
+  optionals: HashMap<String, Vec<String>>,  // Interface name + Optional members
+}
+
+/// Compile the file statements to a function, or get the token of the first statement or
+/// expression that does not have bytecode yet.
+pub fn compile(file: &File) -> Result<Function, Token> {
+  let mut compiler = Compiler {
+    file: file.name.clone(),
+    functions: vec![FunctionState::new(String::from("main"))],
+    optionals: HashMap::new(),
+  };
+
+  for statement in file.statements.iter() {
+    compiler.compile_statement(statement)?;
+  }
+
+  compiler.emit(Instructions::NULL, Token::new_empty());
+  compiler.emit(Instructions::RETURN, Token::new_empty());
+
+  Ok(compiler.end_function())
+}
+
+impl Compiler {
+  fn current(&mut self) -> &mut FunctionState {
+    self.functions.last_mut().unwrap()
+  }
+
+  fn emit(&mut self, instruction: Instructions, token: Token) -> usize {
+    self.current().chunk.emit(instruction, token)
+  }
+
+  fn position(&mut self) -> usize {
+    self.current().chunk.code.len()
+  }
+
+  /// Set the jump target to the next instruction.
+  fn patch(&mut self, position: usize) {
+    let target = self.position();
+
+    self.current().chunk.patch(position, target);
+  }
+
+  fn add_constant(&mut self, object: Box<Objects>) -> usize {
+    self.current().chunk.add_constant(object)
+  }
+
+  fn begin_scope(&mut self) {
+    self.current().scopes.push(Vec::new());
+  }
+
+  fn end_scope(&mut self) {
+    self.current().scopes.pop();
+  }
+
+  /// Add a local variable to the current scope and get its slot.
+  fn declare(&mut self, name: String) -> usize {
+    let function = self.current();
+    let slot = function.locals;
+
+    function.locals += 1;
+    function.scopes.last_mut().unwrap().push((name, slot));
+
+    slot
+  }
+
+  fn end_function(&mut self) -> Function {
+    let function = self.functions.pop().unwrap();
+
+    Function {
+      name: function.name,
+      arguments: function.arguments,
+      locals: function.locals,
+      chunk: function.chunk,
+      file: self.file.clone(),
+    }
+  }
+
+  fn resolve_local(&self, depth: usize, name: &str) -> Option<usize> {
+    for scope in self.functions[depth].scopes.iter().rev() {
+      for (local_name, slot) in scope.iter().rev() {
+        if local_name == name {
+          return Some(*slot);
+        }
+      }
+    }
+
+    None
+  }
+
+  fn resolve_upvalue(&mut self, depth: usize, name: &str) -> Option<usize> {
+    if depth == 0 {
+      return None;
+    }
+
+    let capture = match self.resolve_local(depth - 1, name) {
+      Some(slot) => Capture::LOCAL(slot),
+      None => match self.resolve_upvalue(depth - 1, name) {
+        Some(index) => Capture::UPVALUE(index),
+        None => {
+          return None;
+        },
+      },
+    };
+
+    let function = &mut self.functions[depth];
+
+    // Reuse the upvalue when the variable is already captured.
+    if let Some(index) = function.upvalues.iter().position(|upvalue| upvalue == &capture) {
+      return Some(index);
+    }
+
+    function.upvalues.push(capture);
+
+    Some(function.upvalues.len() - 1)
+  }
+
+  /// Get the declaration token of the variable when it is a const local of the function or
+  /// of an enclosing one.
+  fn resolve_const(&self, depth: usize, name: &str) -> Option<Token> {
+    match self.resolve_local(depth, name) {
+      Some(slot) => self.functions[depth].consts.get(&slot).cloned(),
+      None if depth > 0 => self.resolve_const(depth - 1, name),
+      None => None,
+    }
+  }
+
+  /// Get the class field of the variable, with its const declaration, when it is not a local
+  /// of the method or of a function inside it.
+  fn resolve_field(&self, depth: usize, name: &str) -> Option<Option<Token>> {
+    if self.resolve_local(depth, name).is_some() {
+      return None;
+    }
+
+    match self.functions[depth].fields.get(name) {
+      Some(declaration) => Some(declaration.clone()),
+      None if depth > 0 => self.resolve_field(depth - 1, name),
+      None => None,
+    }
+  }
+
+  /// Push the value of a variable, a local, a field, a captured or a global one.
+  fn compile_get(&mut self, name: String, token: Token) {
+    let depth = self.functions.len() - 1;
+
+    if let Some(slot) = self.resolve_local(depth, &name) {
+      self.emit(Instructions::GETLOCAL(slot), token);
+    } else if self.resolve_field(depth, &name).is_some() {
+      let constant = self.add_constant(StringO::new(name));
+
+      self.compile_get(String::from("this"), token.clone());
+      self.emit(Instructions::MEMBER(constant), token);
+    } else if let Some(index) = self.resolve_upvalue(depth, &name) {
+      self.emit(Instructions::GETUPVALUE(index), token);
+    } else {
+      let constant = self.add_constant(StringO::new(name));
+
+      self.emit(Instructions::GETGLOBAL(constant), token);
+    }
+  }
+
+  /// Set the variable with the value of the top of the stack, keeping the value.
+  fn compile_set(&mut self, name: String, token: Token) {
+    let depth = self.functions.len() - 1;
+
+    if let Some(field) = self.resolve_field(depth, &name) {
+      match field {
+        Some(declaration) => {
+          self.emit(Instructions::SETCONST(declaration), token);
+        },
+        None => {
+          let constant = self.add_constant(StringO::new(name));
+
+          self.compile_get(String::from("this"), token.clone());
+          self.emit(Instructions::SETMEMBER(constant), token);
+        },
+      }
+    } else if let Some(declaration) = self.resolve_const(depth, &name) {
+      self.emit(Instructions::SETCONST(declaration), token);
+    } else if let Some(slot) = self.resolve_local(depth, &name) {
+      self.emit(Instructions::SETLOCAL(slot), token);
+    } else if let Some(index) = self.resolve_upvalue(depth, &name) {
+      self.emit(Instructions::SETUPVALUE(index), token);
+    } else {
+      let constant = self.add_constant(StringO::new(name));
+
+      self.emit(Instructions::SETGLOBAL(constant), token);
+    }
+  }
+
+  /// Leave the tries from the newest until the depth, running their finally blocks.
+  fn compile_exit_tries(&mut self, depth: usize, token: Token) -> Result<(), Token> {
+    let tries = self.current().tries.clone();
+
+    for index in (depth..tries.len()).rev() {
+      if tries[index].has_handler {
+        self.emit(Instructions::POPHANDLER, token.clone());
+      }
+
+      if let Some(finally) = tries[index].finally.clone() {
+        // A jump in the finally only leaves the outer tries.
+        self.current().tries.truncate(index);
+
+        let result = self.compile_statement(&finally);
+
+        self.current().tries = tries.clone();
+
+        result?;
+      }
+    }
+
+    Ok(())
+  }
+
+  fn compile_block(&mut self, statement: &Statements) -> Result<(), Token> {
+    match statement.get_block() {
+      Some(block) => {
+        self.begin_scope();
+
+        for statement in block.get_statements().iter() {
+          self.compile_statement(statement)?;
+        }
+
+        self.end_scope();
+
+        Ok(())
+      },
+      None => self.compile_statement(statement),
+    }
+  }
+
+  /// Compile a loop body, with the jumps of its breaks and continues.
+  fn compile_loop_body(&mut self, body: &Statements) -> Result<LoopState, Token> {
+    let tries = self.current().tries.len();
+
+    self.current().loops.push(LoopState {
+      is_switch: false,
+      tries,
+      breaks: Vec::new(),
+      continues: Vec::new(),
+    });
+
+    let result = self.compile_block(body);
+    let state = self.current().loops.pop().unwrap();
+
+    match result {
+      Ok(_) => Ok(state),
+      Err(token) => Err(token),
+    }
+  }
+
+  fn compile_statement(&mut self, statement: &Statements) -> Result<(), Token> {
+    // Block
+    if statement.get_block().is_some() {
+      return self.compile_block(statement);
+    }
+
+    // Class
+    if let Some(class) = statement.get_class() {
+      return self.compile_class(class);
+    }
+
+    // Continue and break
+    if let Some(continue_break) = statement.get_continue_break() {
+      let token = continue_break.get_token();
+      let is_break = token.token.expect_keyword(&Keywords::BREAK);
+
+      // A continue skips the switches.
+      let index = match self.current().loops.iter().rposition(|state| is_break || !state.is_switch) {
+        Some(index) => index,
+        None => {
+          return Err(token);
+        },
+      };
+
+      let tries = self.current().loops[index].tries;
+
+      self.compile_exit_tries(tries, token.clone())?;
+
+      let jump = self.emit(Instructions::JUMP(0), token);
+
+      if is_break {
+        self.current().loops[index].breaks.push(jump);
+      } else {
+        self.current().loops[index].continues.push(jump);
+      }
+
+      return Ok(());
+    }
+
+    // Do while
+    if let Some(do_while) = statement.get_do_while() {
+      let start = self.position();
+
+      let state = match self.compile_loop_body(&do_while.get_body()) {
+        Ok(state) => state,
+        Err(token) => {
+          return Err(token);
+        },
+      };
+
+      for jump in state.continues.iter() {
+        self.patch(*jump);
+      }
+
+      self.compile_expression(&do_while.get_condition())?;
+
+      let exit = self.emit(Instructions::JUMPIFFALSE(0), do_while.get_condition().token());
+
+      self.emit(Instructions::JUMP(start), do_while.get_condition().token());
+      self.patch(exit);
+
+      for jump in state.breaks.iter() {
+        self.patch(*jump);
+      }
+
+      return Ok(());
+    }
+
+    // Enum
+    if let Some(enum_s) = statement.get_enum() {
+      let mut variants: Vec<(String, bool)> = Vec::new();
+
+      for variant in enum_s.get_variants().iter() {
+        if let Some(value) = variant.get_value() {
+          self.compile_expression(&value)?;
+        }
+
+        variants.push((variant.get_token().value, variant.get_value().is_some()));
+      }
+
+      let name = enum_s.get_name();
+
+      self.emit(Instructions::ENUM(name.value.clone(), variants), name.clone());
+
+      let slot = self.declare(name.value.clone());
+
+      self.emit(Instructions::DEFINELOCAL(slot), name);
+
+      return Ok(());
+    }
+
+    // Export
+    if let Some(export) = statement.get_export() {
+      let value = export.get_value();
+
+      self.compile_statement(&value)?;
+
+      let name = if let Some(variable) = value.get_variable() {
+        Some(variable.get_name())
+      } else if let Some(function) = value.get_function() {
+        Some(function.get_name())
+      } else if let Some(class) = value.get_class() {
+        Some(class.get_name())
+      } else if let Some(enum_s) = value.get_enum() {
+        Some(enum_s.get_name())
+      } else {
+        value.get_expression().and_then(|expression| expression.get_expression().get_identifier()).map(|identifier| identifier.get_token())
+      };
+
+      // Add the value to the file store, so the files that import it can get it.
+      if let Some(name) = name {
+        let constant = self.add_constant(StringO::new(name.value.clone()));
+
+        self.compile_get(name.value.clone(), name.clone());
+        self.emit(Instructions::DEFINEGLOBAL(constant), name);
+      }
+
+      return Ok(());
+    }
+
+    // Expression
+    if let Some(expression) = statement.get_expression() {
+      self.compile_expression(&expression.get_expression())?;
+
+      self.emit(Instructions::POP, expression.get_token());
+
+      return Ok(());
+    }
+
+    // For
+    if let Some(for_s) = statement.get_for() {
+      return self.compile_for(&for_s.get_condition(), &for_s.get_body());
+    }
+
+    // Function
+    if let Some(function) = statement.get_function() {
+      let name = function.get_name();
+      let slot = self.declare(name.value.clone());
+
+      // The variable is declared before the function, so the function can call itself.
+      self.emit(Instructions::NULL, name.clone());
+      self.emit(Instructions::DEFINELOCAL(slot), name.clone());
+
+      self.compile_function(name.value.clone(), &function.get_arguments(), &function.get_body(), name.clone(), None)?;
+
+      self.emit(Instructions::SETLOCAL(slot), name.clone());
+      self.emit(Instructions::POP, name);
+
+      return Ok(());
+    }
+
+    // If else
+    if let Some(if_else) = statement.get_if_else() {
+      let mut ends: Vec<usize> = Vec::new();
+
+      for condition in if_else.get_conditions().iter() {
+        self.compile_expression(&condition.get_condition())?;
+
+        let next = self.emit(Instructions::JUMPIFFALSE(0), condition.get_token());
+
+        self.compile_block(&condition.get_consequence())?;
+
+        ends.push(self.emit(Instructions::JUMP(0), condition.get_token()));
+
+        self.patch(next);
+      }
+
+      if let Some(alternative) = if_else.get_alternative() {
+        self.compile_block(&alternative)?;
+      }
+
+      for jump in ends.iter() {
+        self.patch(*jump);
+      }
+
+      return Ok(());
+    }
+
+    // Import
+    if let Some(import) = statement.get_import() {
+      self.emit(Instructions::IMPORT(import.clone()), import.get_token());
+
+      return Ok(());
+    }
+
+    // Interface
+    if let Some(interface) = statement.get_interface() {
+      self.optionals.insert(interface.get_name().value, interface.get_optionals());
+      return Ok(());
+    }
+
+    // Return
+    if let Some(return_s) = statement.get_return() {
+      let token = return_s.get_token();
+
+      match return_s.get_value() {
+        Some(value) => {
+          self.compile_expression(&value)?;
+        },
+        None => {
+          self.emit(Instructions::NULL, token.clone());
+        },
+      }
+
+      self.compile_exit_tries(0, token.clone())?;
+
+      self.emit(Instructions::RETURN, token);
+
+      return Ok(());
+    }
+
+    // Switch
+    if let Some(switch) = statement.get_switch() {
+      return self.compile_switch(statement, switch.get_value(), switch.get_cases(), switch.get_default());
+    }
+
+    // Throw
+    if let Some(throw) = statement.get_throw() {
+      self.compile_expression(&throw.get_value())?;
+
+      self.emit(Instructions::THROW, throw.get_token());
+
+      return Ok(());
+    }
+
+    // Try
+    if let Some(try_s) = statement.get_try() {
+      return self.compile_try(try_s);
+    }
+
+    // Variable
+    if let Some(variable) = statement.get_variable() {
+      match variable.get_value() {
+        Some(value) => {
+          self.compile_expression(&value)?;
+        },
+        None => {
+          self.emit(Instructions::NULL, variable.get_name());
+        },
+      }
+
+      self.compile_optionals(&variable.get_type());
+
+      let slot = self.declare(variable.get_name().value);
+
+      if variable.get_token().token.expect_keyword(&Keywords::CONST) {
+        self.current().consts.insert(slot, variable.get_name());
+      }
+
+      self.emit(Instructions::DEFINELOCAL(slot), variable.get_name());
+
+      return Ok(());
+    }
+
+    // While
+    if let Some(while_s) = statement.get_while() {
+      let start = self.position();
+
+      self.compile_expression(&while_s.get_condition())?;
+
+      let exit = self.emit(Instructions::JUMPIFFALSE(0), while_s.get_condition().token());
+
+      let state = match self.compile_loop_body(&while_s.get_body()) {
+        Ok(state) => state,
+        Err(token) => {
+          return Err(token);
+        },
+      };
+
+      self.emit(Instructions::JUMP(start), while_s.get_condition().token());
+      self.patch(exit);
+
+      for jump in state.continues.iter() {
+        self.current().chunk.patch(*jump, start);
+      }
+
+      for jump in state.breaks.iter() {
+        self.patch(*jump);
+      }
+
+      return Ok(());
+    }
+
+    Err(statement.token())
+  }
+
+  fn compile_for(&mut self, condition: &Expressions, body: &Statements) -> Result<(), Token> {
+    if let Some(for_condition) = condition.get_for_condition() {
+      return self.compile_for_condition(for_condition, body);
+    }
+
+    let infix = match condition.get_infix() {
+      Some(infix) => infix,
+      None => {
+        return Err(condition.token());
+      },
+    };
+
+    let is_of = infix.get_token().token.expect_keyword(&Keywords::OF);
+
+    // Get the names of the element, or the key and the value.
+    let mut names: Vec<Token> = Vec::new();
+
+    if infix.get_token().token.expect_keyword(&Keywords::IN) {
+      match infix.get_left().get_identifier() {
+        Some(identifier) => names.push(identifier.get_token()),
+        None => {
+          return Err(infix.get_token());
+        },
+      }
+    } else if is_of {
+      if let Some(array) = infix.get_left().get_array() {
+        for element in array.get_data().iter() {
+          match element.get_identifier() {
+            Some(identifier) => names.push(identifier.get_token()),
+            None => {
+              return Err(element.token());
+            },
+          }
+        }
+      }
+
+      if names.len() != 2 {
+        return Err(infix.get_token());
+      }
+    } else {
+      return Err(infix.get_token());
+    }
+
+    let right = match infix.get_right() {
+      Some(right) => right,
+      None => {
+        return Err(infix.get_token());
+      },
+    };
+
+    self.begin_scope();
+
+    self.compile_expression(&right)?;
+
+    // Keep the collection and the index in hidden locals.
+    self.emit(Instructions::ITERATOR(is_of), right.token());
+
+    let collection = self.declare(String::new());
+
+    self.emit(Instructions::DEFINELOCAL(collection), right.token());
+
+    let constant = self.add_constant(Number::new(0.0));
+
+    self.emit(Instructions::CONSTANT(constant), infix.get_token());
+
+    let index = self.declare(String::new());
+
+    self.emit(Instructions::DEFINELOCAL(index), infix.get_token());
+
+    let start = self.emit(Instructions::NEXT(collection, index, 0), infix.get_token());
+
+    // Each iteration has new variables, so the closures keep their own values.
+    self.begin_scope();
+
+    for name in names.iter().rev() {
+      let slot = self.declare(name.value.clone());
+
+      self.emit(Instructions::DEFINELOCAL(slot), name.clone());
+    }
+
+    let state = match self.compile_loop_body(body) {
+      Ok(state) => state,
+      Err(token) => {
+        return Err(token);
+      },
+    };
+
+    self.end_scope();
+
+    self.emit(Instructions::JUMP(start), infix.get_token());
+    self.patch(start);
+
+    for jump in state.continues.iter() {
+      self.current().chunk.patch(*jump, start);
+    }
+
+    for jump in state.breaks.iter() {
+      self.patch(*jump);
+    }
+
+    self.end_scope();
+
+    Ok(())
+  }
+
+  /// Compile a `for (i = 0; i < length; i++)` loop, the variable is declared in the loop.
+  fn compile_for_condition(&mut self, for_condition: ForCondition, body: &Statements) -> Result<(), Token> {
+    let first = match for_condition.get_first().get_infix() {
+      Some(first) if first.is_variable_set() && first.get_left().get_identifier().is_some() => first,
+      _ => {
+        return Err(for_condition.get_token());
+      },
+    };
+
+    self.begin_scope();
+
+    self.compile_expression(&first.get_right().unwrap())?;
+
+    let name = first.get_left().token();
+    let slot = self.declare(name.value.clone());
+
+    self.emit(Instructions::DEFINELOCAL(slot), name);
+
+    let start = self.position();
+
+    self.compile_expression(&for_condition.get_second())?;
+
+    let exit = self.emit(Instructions::JUMPIFFALSE(0), for_condition.get_second().token());
+
+    let state = match self.compile_loop_body(body) {
+      Ok(state) => state,
+      Err(token) => {
+        return Err(token);
+      },
+    };
+
+    for jump in state.continues.iter() {
+      self.patch(*jump);
+    }
+
+    self.compile_expression(&for_condition.get_third())?;
+
+    self.emit(Instructions::POP, for_condition.get_third().token());
+    self.emit(Instructions::JUMP(start), for_condition.get_token());
+    self.patch(exit);
+
+    for jump in state.breaks.iter() {
+      self.patch(*jump);
+    }
+
+    self.end_scope();
+
+    Ok(())
+  }
+
+  fn compile_switch(
+    &mut self,
+    statement: &Statements,
+    value: Box<Expressions>,
+    cases: Vec<SwitchCase>,
+    default: Option<Box<Statements>>,
+  ) -> Result<(), Token> {
+    let token = statement.token();
+
+    self.begin_scope();
+
+    self.compile_expression(&value)?;
+
+    let slot = self.declare(String::new());
+
+    self.emit(Instructions::DEFINELOCAL(slot), token.clone());
+
+    // Compare the value with the cases, in order.
+    let mut case_jumps: Vec<Vec<usize>> = Vec::new();
+
+    for case in cases.iter() {
+      let mut jumps: Vec<usize> = Vec::new();
+
+      for case_value in case.get_values().iter() {
+        self.emit(Instructions::GETLOCAL(slot), case_value.token());
+
+        self.compile_expression(case_value)?;
+
+        self.emit(Instructions::BINARY(Signs::NOTEQUAL), case_value.token());
+
+        jumps.push(self.emit(Instructions::JUMPIFFALSE(0), case_value.token()));
+      }
+
+      case_jumps.push(jumps);
+    }
+
+    let default_jump = self.emit(Instructions::JUMP(0), token.clone());
+
+    let tries = self.current().tries.len();
+
+    self.current().loops.push(LoopState {
+      is_switch: true,
+      tries,
+      breaks: Vec::new(),
+      continues: Vec::new(),
+    });
+
+    let mut ends: Vec<usize> = Vec::new();
+
+    for (case, jumps) in cases.iter().zip(case_jumps.iter()) {
+      for jump in jumps.iter() {
+        self.patch(*jump);
+      }
+
+      self.compile_block(&case.get_consequence())?;
+
+      ends.push(self.emit(Instructions::JUMP(0), case.get_token()));
+    }
+
+    self.patch(default_jump);
+
+    if let Some(default) = default {
+      self.compile_block(&default)?;
+    }
+
+    let state = self.current().loops.pop().unwrap();
+
+    for jump in ends.iter().chain(state.breaks.iter()) {
+      self.patch(*jump);
+    }
+
+    self.end_scope();
+
+    Ok(())
+  }
+
+  fn compile_try(&mut self, try_s: Try) -> Result<(), Token> {
+    let token = try_s.get_token();
+    let finally = try_s.get_finally();
+
+    // Body
+    let handler = self.emit(Instructions::PUSHHANDLER(0), token.clone());
+
+    self.current().tries.push(TryState {
+      has_handler: true,
+      finally: finally.clone(),
+    });
+
+    let result = self.compile_block(&try_s.get_body());
+
+    self.current().tries.pop();
+
+    result?;
+
+    self.emit(Instructions::POPHANDLER, token.clone());
+
+    let mut ends: Vec<usize> = vec![self.emit(Instructions::JUMP(0), token.clone())];
+
+    self.patch(handler);
+
+    // Catch, with the error on the stack.
+    if let (Some(name), Some(catch)) = (try_s.get_catch_name(), try_s.get_catch()) {
+      self.begin_scope();
+
+      self.emit(Instructions::CATCH, name.clone());
+
+      let slot = self.declare(name.value.clone());
+
+      self.emit(Instructions::DEFINELOCAL(slot), name.clone());
+
+      // The finally also runs when the catch throws.
+      let rethrow = match finally {
+        Some(_) => {
+          let rethrow = self.emit(Instructions::PUSHHANDLER(0), name.clone());
+
+          self.current().tries.push(TryState {
+            has_handler: true,
+            finally: finally.clone(),
+          });
+
+          Some(rethrow)
+        },
+        None => None,
+      };
+
+      let result = self.compile_block(&catch);
+
+      self.end_scope();
+
+      result?;
+
+      if let Some(rethrow) = rethrow {
+        self.current().tries.pop();
+
+        self.emit(Instructions::POPHANDLER, name.clone());
+
+        ends.push(self.emit(Instructions::JUMP(0), name.clone()));
+
+        self.patch(rethrow);
+      } else {
+        ends.push(self.emit(Instructions::JUMP(0), name));
+      }
+    }
+
+    if let Some(finally) = finally {
+      // Run the finally with the uncaught error, and throw it again.
+      self.begin_scope();
+
+      let slot = self.declare(String::new());
+
+      self.emit(Instructions::DEFINELOCAL(slot), token.clone());
+
+      self.compile_block(&finally)?;
+
+      self.emit(Instructions::GETLOCAL(slot), token.clone());
+      self.emit(Instructions::THROW, token.clone());
+
+      self.end_scope();
+
+      for jump in ends.iter() {
+        self.patch(*jump);
+      }
+
+      ends.clear();
+
+      self.compile_block(&finally)?;
+    }
+
+    for jump in ends.iter() {
+      self.patch(*jump);
+    }
+
+    Ok(())
+  }
+
+  /// Declare the arguments of the new function, and get them with their slots.
+  fn compile_arguments(&mut self, arguments: &[Box<Expressions>]) -> Result<Vec<(usize, Argument)>, Token> {
+    let mut slots: Vec<(usize, Argument)> = Vec::new();
+
+    for argument in arguments.iter() {
+      let argument = match argument.get_argument() {
+        Some(function_argument) => function_argument,
+        None => {
+          return Err(argument.token());
+        },
+      };
+
+      let slot = self.declare(argument.get_token().value);
+
+      if argument.is_const() {
+        self.current().consts.insert(slot, argument.get_token());
+      }
+
+      slots.push((slot, argument));
+    }
+
+    self.current().arguments = self.current().locals;
+
+    // Evaluate the default values of the missing arguments.
+    for (slot, argument) in slots.iter() {
+      if let Some(value) = argument.get_value() {
+        let skip = self.emit(Instructions::JUMPIFARGUMENT(*slot, 0), argument.get_token());
+
+        self.compile_expression(&value)?;
+
+        self.emit(Instructions::DEFINELOCAL(*slot), argument.get_token());
+        self.patch(skip);
+      }
+
+      if self.optionals.contains_key(&argument.get_type().value) {
+        self.emit(Instructions::GETLOCAL(*slot), argument.get_token());
+        self.compile_optionals(&argument.get_type());
+        self.emit(Instructions::DEFINELOCAL(*slot), argument.get_token());
+      }
+    }
+
+    Ok(slots)
+  }
+
+  /// Add the missing optional members when the data type is an interface with them.
+  fn compile_optionals(&mut self, data_type: &Token) {
+    if let Some(optionals) = self.optionals.get(&data_type.value).cloned() {
+      self.emit(Instructions::OPTIONALS(optionals), data_type.clone());
+    }
+  }
+
+  /// Compile a function in a new function state and push the closure. The methods get the
+  /// instance as `this`, before the arguments.
+  fn compile_function(
+    &mut self,
+    name: String,
+    arguments: &[Box<Expressions>],
+    body: &Statements,
+    token: Token,
+    fields: Option<HashMap<String, Option<Token>>>,
+  ) -> Result<(), Token> {
+    self.functions.push(FunctionState::new(name));
+
+    if let Some(fields) = fields {
+      self.current().fields = fields;
+      self.declare(String::from("this"));
+    }
+
+    self.compile_arguments(arguments)?;
+
+    self.compile_block(body)?;
+
+    self.emit(Instructions::NULL, body.token());
+    self.emit(Instructions::RETURN, body.token());
+
+    self.end_closure(token);
+
+    Ok(())
+  }
+
+  /// End the new function and push its closure.
+  fn end_closure(&mut self, token: Token) {
+    let upvalues = self.current().upvalues.clone();
+    let function = self.end_function();
+
+    self.current().chunk.functions.push(Rc::new(function));
+
+    let index = self.current().chunk.functions.len() - 1;
+
+    self.emit(Instructions::CLOSURE(index, upvalues), token);
+  }
+
+  /// Compile a class to its constructor and its methods. The constructor gets the new instance
+  /// as `this`, and adds the arguments and the fields to it.
+  fn compile_class(&mut self, class: Class) -> Result<(), Token> {
+    let name = class.get_name();
+    let slot = self.declare(name.value.clone());
+
+    // The variable is declared before the class, so the methods can use it.
+    self.emit(Instructions::NULL, name.clone());
+    self.emit(Instructions::DEFINELOCAL(slot), name.clone());
+
+    self.functions.push(FunctionState::new(name.value.clone()));
+
+    let this = self.declare(String::from("this"));
+
+    let arguments = match self.compile_arguments(&class.get_arguments()) {
+      Ok(arguments) => arguments,
+      Err(token) => {
+        return Err(token);
+      },
+    };
+
+    let mut fields: HashMap<String, Option<Token>> = HashMap::new();
+
+    for (argument_slot, argument) in arguments.iter() {
+      let token = argument.get_token();
+      let constant = self.add_constant(StringO::new(token.value.clone()));
+
+      self.emit(Instructions::GETLOCAL(*argument_slot), token.clone());
+      self.emit(Instructions::GETLOCAL(this), token.clone());
+      self.emit(Instructions::SETMEMBER(constant), token.clone());
+      self.emit(Instructions::POP, token.clone());
+
+      fields.insert(token.value.clone(), if argument.is_const() { Some(token) } else { None });
+    }
+
+    // The fields are also locals of the constructor, for the next fields.
+    for field in class.get_fields().iter() {
+      let token = field.get_name();
+
+      match field.get_value() {
+        Some(value) => {
+          self.compile_expression(&value)?;
+        },
+        None => {
+          self.emit(Instructions::NULL, token.clone());
+        },
+      }
+
+      let field_slot = self.declare(token.value.clone());
+      let constant = self.add_constant(StringO::new(token.value.clone()));
+
+      self.emit(Instructions::DEFINELOCAL(field_slot), token.clone());
+      self.emit(Instructions::GETLOCAL(field_slot), token.clone());
+      self.emit(Instructions::GETLOCAL(this), token.clone());
+      self.emit(Instructions::SETMEMBER(constant), token.clone());
+      self.emit(Instructions::POP, token.clone());
+
+      let is_const = field.get_token().token.expect_keyword(&Keywords::CONST);
+
+      fields.insert(token.value.clone(), if is_const { Some(token) } else { None });
+    }
+
+    self.emit(Instructions::GETLOCAL(this), name.clone());
+    self.emit(Instructions::RETURN, name.clone());
+    self.end_closure(name.clone());
+
+    let mut methods: Vec<String> = Vec::new();
+
+    for method in class.get_methods().iter() {
+      let method_name = method.get_name();
+
+      self.compile_function(
+        method_name.value.clone(),
+        &method.get_arguments(),
+        &method.get_body(),
+        method_name.clone(),
+        Some(fields.clone()),
+      )?;
+
+      methods.push(method_name.value);
+    }
+
+    self.emit(Instructions::CLASS(name.value.clone(), methods), name.clone());
+    self.emit(Instructions::SETLOCAL(slot), name.clone());
+    self.emit(Instructions::POP, name);
+
+    Ok(())
+  }
+
+  fn compile_expressions(&mut self, expressions: &[Box<Expressions>]) -> Result<(), Token> {
+    for expression in expressions.iter() {
+      self.compile_expression(expression)?;
+    }
+
+    Ok(())
+  }
+
+  fn compile_expression(&mut self, expression: &Expressions) -> Result<(), Token> {
+    // Anonymous function
+    if let Some(anonymous_function) = expression.get_anonymous_function() {
+      return self.compile_function(
+        String::from("anonymous"),
+        &anonymous_function.get_arguments(),
+        &anonymous_function.get_body(),
+        anonymous_function.get_token(),
+        None,
+      );
+    }
+
+    // Array
+    if let Some(array) = expression.get_array() {
+      self.compile_expressions(&array.get_data())?;
+
+      self.emit(Instructions::ARRAY(array.get_data().len()), array.get_token());
+
+      return Ok(());
+    }
+
+    // Array index
+    if let Some(array_index) = expression.get_array_index() {
+      self.compile_get(array_index.get_token().value, array_index.get_token());
+
+      self.compile_expression(&array_index.get_index())?;
+
+      self.emit(Instructions::INDEX, array_index.get_token());
+
+      return Ok(());
+    }
+
+    // Boolean
+    if let Some(boolean) = expression.get_boolean() {
+      let constant = self.add_constant(Boolean::new(boolean.get_value()));
+
+      self.emit(Instructions::CONSTANT(constant), boolean.get_token());
+
+      return Ok(());
+    }
+
+    // Call
+    if let Some(call) = expression.get_call() {
+      self.compile_get(call.get_token().value, call.get_token());
+
+      self.compile_expressions(&call.get_arguments())?;
+
+      self.emit(Instructions::CALL(call.get_arguments().len()), call.get_token());
+
+      return Ok(());
+    }
+
+    // HashMap
+    if let Some(hashmap) = expression.get_hashmap() {
+      let mut keys: Vec<String> = Vec::new();
+
+      for (key, value) in hashmap.get_items() {
+        self.compile_expression(&value)?;
+
+        keys.push(key);
+      }
+
+      self.emit(Instructions::HASHMAP(keys), hashmap.get_token());
+
+      return Ok(());
+    }
+
+    // Identifier
+    if let Some(identifier) = expression.get_identifier() {
+      self.compile_get(identifier.get_value(), identifier.get_token());
+
+      return Ok(());
+    }
+
+    // Infix
+    if let Some(infix) = expression.get_infix() {
+      return self.compile_infix(&infix);
+    }
+
+    // Null
+    if let Some(null) = expression.get_null() {
+      self.emit(Instructions::NULL, null.get_token());
+
+      return Ok(());
+    }
+
+    // Number
+    if let Some(number) = expression.get_number() {
+      let constant = self.add_constant(Number::new(number.get_value()));
+
+      self.emit(Instructions::CONSTANT(constant), number.get_token());
+
+      return Ok(());
+    }
+
+    // Prefix
+    if let Some(prefix) = expression.get_prefix() {
+      let token = prefix.get_token();
+
+      let instruction = if token.token.expect_sign(&Signs::NOT) {
+        Instructions::NOT
+      } else if token.token.expect_sign(&Signs::MINUS) {
+        Instructions::NEGATE
+      } else if token.token.expect_sign(&Signs::PLUSPLUS) {
+        return self.compile_assignment(&prefix.get_right(), Some(Signs::PLUS), None, token);
+      } else if token.token.expect_sign(&Signs::MINUSMINUS) {
+        return self.compile_assignment(&prefix.get_right(), Some(Signs::MINUS), None, token);
+      } else {
+        return Err(token);
+      };
+
+      self.compile_expression(&prefix.get_right())?;
+
+      self.emit(instruction, token);
+
+      return Ok(());
+    }
+
+    // String
+    if let Some(string) = expression.get_string() {
+      let value = string.get_value();
+      let constant = self.add_constant(StringO::new(value[1..value.len() - 1].to_string()));
+
+      self.emit(Instructions::CONSTANT(constant), string.get_token());
+
+      return Ok(());
+    }
+
+    // Suffix, the value is the one before the change.
+    if let Some(suffix) = expression.get_suffix() {
+      let token = suffix.get_token();
+
+      let operator = if token.token.expect_sign(&Signs::PLUSPLUS) {
+        Signs::PLUS
+      } else if token.token.expect_sign(&Signs::MINUSMINUS) {
+        Signs::MINUS
+      } else {
+        return Err(token);
+      };
+
+      self.compile_expression(&suffix.get_left())?;
+
+      self.compile_assignment(&suffix.get_left(), Some(operator), None, token.clone())?;
+
+      self.emit(Instructions::POP, token);
+
+      return Ok(());
+    }
+
+    // Template
+    if let Some(template) = expression.get_template() {
+      self.compile_expressions(&template.get_parts())?;
+
+      self.emit(Instructions::TEMPLATE(template.get_parts().len()), template.get_token());
+
+      return Ok(());
+    }
+
+    Err(expression.token())
+  }
+
+  fn compile_infix(&mut self, infix: &Infix) -> Result<(), Token> {
+    let token = infix.get_token();
+
+    // Is
+    if infix.is_type() {
+      self.compile_expression(&infix.get_left())?;
+
+      match infix.get_right_type() {
+        Some(right_type) => {
+          self.emit(Instructions::IS(right_type), token);
+        },
+        None => {
+          return Err(token);
+        },
+      }
+
+      return Ok(());
+    }
+
+    let right = match infix.get_right() {
+      Some(right) => right,
+      None => {
+        return Err(token);
+      },
+    };
+
+    // Method
+    if infix.is_method() {
+      self.compile_expression(&infix.get_left())?;
+
+      if let Some(identifier) = right.get_identifier() {
+        let constant = self.add_constant(StringO::new(identifier.get_value()));
+
+        self.emit(Instructions::MEMBER(constant), identifier.get_token());
+      } else if let Some(call) = right.get_call() {
+        let constant = self.add_constant(StringO::new(call.get_token().value));
+
+        self.compile_expressions(&call.get_arguments())?;
+
+        self.emit(Instructions::METHOD(constant, call.get_arguments().len()), call.get_token());
+      } else if let Some(array_index) = right.get_array_index() {
+        let constant = self.add_constant(StringO::new(array_index.get_token().value));
+
+        self.emit(Instructions::MEMBER(constant), array_index.get_token());
+
+        self.compile_expression(&array_index.get_index())?;
+
+        self.emit(Instructions::INDEX, array_index.get_token());
+      } else {
+        return Err(right.token());
+      }
+
+      return Ok(());
+    }
+
+    // Variable set
+    if infix.is_variable_set() {
+      let operator = match token.token.get_sign() {
+        Some(Signs::ASSIGN) => None,
+        Some(Signs::PLUSASSIGN) => Some(Signs::PLUS),
+        Some(Signs::MINUSASSIGN) => Some(Signs::MINUS),
+        Some(Signs::MULTIPLYASSIGN) => Some(Signs::MULTIPLY),
+        Some(Signs::DIVIDEASSIGN) => Some(Signs::DIVIDE),
+        Some(Signs::MODULEASSIGN) => Some(Signs::MODULE),
+        _ => {
+          return Err(token);
+        },
+      };
+
+      return self.compile_assignment(&infix.get_left(), operator, Some(&right), token);
+    }
+
+    // Infix
+    if infix.is_infix() {
+      let sign = match token.token.get_sign() {
+        Some(sign) => sign,
+        None => {
+          return Err(token);
+        },
+      };
+
+      self.compile_expression(&infix.get_left())?;
+
+      // Skip the right expression when the left value decides the condition.
+      let skip = match sign {
+        Signs::AND => Some(self.emit(Instructions::AND(0), token.clone())),
+        Signs::OR => Some(self.emit(Instructions::OR(0), token.clone())),
+        _ => None,
+      };
+
+      self.compile_expression(&right)?;
+
+      self.emit(Instructions::BINARY(sign), token);
+
+      if let Some(skip) = skip {
+        self.patch(skip);
+      }
+
+      return Ok(());
+    }
+
+    Err(token)
+  }
+
+  /// Push the new value of an assignment, the right value or `1` for the `++` and `--`, with the
+  /// operator applied to the current value.
+  fn compile_assignment_value(
+    &mut self,
+    operator: &Option<Signs>,
+    right: Option<&Expressions>,
+    token: Token,
+  ) -> Result<(), Token> {
+    match right {
+      Some(right) => {
+        self.compile_expression(right)?;
+      },
+      None => {
+        let constant = self.add_constant(Number::new(1.0));
+
+        self.emit(Instructions::CONSTANT(constant), token.clone());
+      },
+    }
+
+    if let Some(operator) = operator {
+      self.emit(Instructions::BINARY(operator.clone()), token);
+    }
+
+    Ok(())
+  }
+
+  /// Set a variable, an array element or an instance field, keeping the new value.
+  fn compile_assignment(
+    &mut self,
+    left: &Expressions,
+    operator: Option<Signs>,
+    right: Option<&Expressions>,
+    token: Token,
+  ) -> Result<(), Token> {
+    if let Some(identifier) = left.get_identifier() {
+      if operator.is_some() {
+        self.compile_get(identifier.get_value(), identifier.get_token());
+      }
+
+      self.compile_assignment_value(&operator, right, token)?;
+
+      self.compile_set(identifier.get_value(), identifier.get_token());
+    } else if let Some(array_index) = left.get_array_index() {
+      let name = array_index.get_token();
+
+      self.compile_get(name.value.clone(), name.clone());
+
+      self.compile_expression(&array_index.get_index())?;
+
+      // Get the current element for the operator.
+      if operator.is_some() {
+        self.compile_get(name.value.clone(), name.clone());
+
+        self.compile_expression(&array_index.get_index())?;
+
+        self.emit(Instructions::INDEX, name.clone());
+      }
+
+      self.compile_assignment_value(&operator, right, token)?;
+
+      self.emit(Instructions::SETINDEX, array_index.get_index().token());
+
+      self.compile_set(name.value.clone(), name);
+    } else if let Some(member) = left.get_infix().filter(|infix| infix.is_method()) {
+      let name = match member.get_right().and_then(|right| right.get_identifier()) {
+        Some(identifier) => identifier.get_token(),
+        None => {
+          return Err(left.token());
+        },
+      };
+
+      let constant = self.add_constant(StringO::new(name.value.clone()));
+
+      // Get the current field for the operator.
+      if operator.is_some() {
+        self.compile_expression(&member.get_left())?;
+
+        self.emit(Instructions::MEMBER(constant), name.clone());
+      }
+
+      self.compile_assignment_value(&operator, right, token)?;
+
+      self.compile_expression(&member.get_left())?;
+
+      self.emit(Instructions::SETMEMBER(constant), name);
+    } else {
+      return Err(left.token());
+    }
+
+    Ok(())
+  }
+}
@@ -0,0 +1,863 @@
+use crate::{
+  compiler::{
+    Array,
+    Boolean,
+    builtins::{
+      get_builtin_for_identifier,
+      get_builtin_member,
+      Members,
+    },
+    call_anonymous_function,
+    Cell,
+    Class,
+    Closure,
+    EnumO,
+    EnumVariant,
+    equal_objects,
+    Error,
+    Frame,
+    get_index,
+    HashItem,
+    HashMap,
+    import,
+    Instance,
+    Null,
+    Number,
+    object_is_type,
+    Objects,
+    StringO,
+  },
+  Environment,
+};
+
+use sflyn_parser::tokens::{
+  Signs,
+  Token,
+};
+
+use std::{
+  cell::RefCell,
+  rc::Rc,
+};
+
+use super::{
+  Capture,
+  Function,
+  Instructions,
+};
+
+/// The position to continue when an error is thrown in a try.
+struct Handler {
+  target: usize,
+  stack: usize,
+}
+
+struct CallFrame {
+  closure: Closure,
+  ip: usize,
+  stack: usize,
+  locals: Vec<Cell>,
+  arguments: usize,
+  handlers: Vec<Handler>,
+  call: Option<Frame>,
+}
+
+pub struct Machine<'a> {
+  environment: &'a mut Environment,
+  stack: Vec<Box<Objects>>,
+  frames: Vec<CallFrame>,
+}
+
+fn new_cell(object: Box<Objects>) -> Cell {
+  Rc::new(RefCell::new(object))
+}
+
+fn unknown_infix(left: &Objects, right: &Objects, token: Token) -> Box<Objects> {
+  Error::new(
+    format!("Unknown infix: {} {} {}", left.string(), token.value, right.string()),
+    token,
+  )
+}
+
+fn const_error(token: Token, declaration: Token) -> Box<Objects> {
+  Error::new_with_label(
+    format!("`{}` is a const.", token.value),
+    token,
+    String::from("declared as a const here."),
+    declaration,
+  )
+}
+
+/// Evaluate an infix operator, like the tree-walking evaluator.
+fn binary(sign: &Signs, left: Box<Objects>, right: Box<Objects>, token: Token) -> Result<Box<Objects>, Box<Objects>> {
+  // Check if left and right objects are numbers.
+  if let (Some(left_number), Some(right_number)) = (left.get_number(), right.get_number()) {
+    let left_value = left_number.get_value();
+    let right_value = right_number.get_value();
+
+    return match sign {
+      Signs::PLUS => Ok(Number::new(left_value + right_value)),
+      Signs::MINUS => Ok(Number::new(left_value - right_value)),
+      Signs::MULTIPLY => Ok(Number::new(left_value * right_value)),
+      Signs::DIVIDE => Ok(Number::new(left_value / right_value)),
+      Signs::EMPOWERMENT | Signs::CARER => Ok(Number::new(left_value.powf(right_value))),
+      Signs::MODULE => Ok(Number::new(left_value % right_value)),
+      Signs::LESSTHAN => Ok(Boolean::new(left_value < right_value)),
+      Signs::LESSOREQUALTHAN => Ok(Boolean::new(left_value <= right_value)),
+      Signs::GREATERTHAN => Ok(Boolean::new(left_value > right_value)),
+      Signs::GREATEROREQUALTHAN => Ok(Boolean::new(left_value >= right_value)),
+      Signs::EQUAL => Ok(Boolean::new(left_value == right_value)),
+      Signs::NOTEQUAL => Ok(Boolean::new(left_value != right_value)),
+      _ => Err(unknown_infix(&left, &right, token)),
+    };
+  }
+
+  match sign {
+    // Check if left or right object is a string.
+    Signs::PLUS if left.get_string().is_some() || right.get_string().is_some() => {
+      Ok(StringO::new(left.string() + &right.string()))
+    },
+    Signs::EQUAL => Ok(equal_objects(left, right)),
+    Signs::NOTEQUAL => Ok(Boolean::new(!Boolean::is_truthy(equal_objects(left, right)))),
+    Signs::OR => {
+      // Null, empty strings and false booleans return the right object.
+      let return_right = match left.as_ref() {
+        Objects::NULL(_) => true,
+        Objects::STRING(string) => string.get_value().is_empty(),
+        Objects::BOOLEAN(boolean) => !boolean.get_value(),
+        _ => false,
+      };
+
+      Ok(if return_right { right } else { left })
+    },
+    Signs::AND => match (left.get_boolean(), right.get_boolean()) {
+      (Some(left_boolean), Some(right_boolean)) => Ok(Boolean::new(left_boolean.get_value() && right_boolean.get_value())),
+      _ => Err(unknown_infix(&left, &right, token)),
+    },
+    _ => Err(unknown_infix(&left, &right, token)),
+  }
+}
+
+impl<'a> Machine<'a> {
+  pub fn new(environment: &'a mut Environment) -> Machine<'a> {
+    Machine {
+      environment,
+      stack: Vec::new(),
+      frames: Vec::new(),
+    }
+  }
+
+  fn pop(&mut self) -> Box<Objects> {
+    self.stack.pop().unwrap()
+  }
+
+  fn frame(&mut self) -> &mut CallFrame {
+    self.frames.last_mut().unwrap()
+  }
+
+  /// Get the running function calls, from the outermost.
+  fn get_running_frames(&self) -> Vec<Frame> {
+    let mut frames = self.environment.frames.clone();
+
+    for frame in self.frames.iter() {
+      if let Some(call) = frame.call.clone() {
+        frames.push(call);
+      }
+    }
+
+    frames
+  }
+
+  /// Get a member of the object, the data of a hashmap, a field of an instance, a variant of an enum
+  /// or the standard library of the data type.
+  fn get_member(&self, object: &Objects, name: &str, token: Token) -> Box<Objects> {
+    match object {
+      Objects::HASHMAP(hashmap) => {
+        if let Some(item) = hashmap.get_data().into_iter().find(|item| item.key == name) {
+          return item.value;
+        }
+      },
+      Objects::INSTANCE(instance) => {
+        if let Some(field) = instance.get_field(&name.to_string()) {
+          return field;
+        }
+      },
+      Objects::ENUM(enum_o) => {
+        let variant = enum_o.get_variants().into_iter().find(|variant| {
+          variant.get_enum_variant().is_some_and(|variant| variant.name == name)
+        });
+
+        if let Some(variant) = variant {
+          return variant;
+        }
+      },
+      Objects::ENUMVARIANT(variant) => match name {
+        "name" => {
+          return StringO::new(variant.name.clone());
+        },
+        "value" => {
+          return variant.value.clone();
+        },
+        _ => {},
+      },
+      _ => {},
+    }
+
+    // Get the native property of the value, like the string `length`.
+    if let Some(Members::PROPERTY(property)) = get_builtin_member(object, name) {
+      return (property)(object);
+    }
+
+    get_builtin_for_identifier(token)
+  }
+
+  /// Call a function object, pushing a frame for the compiled ones.
+  fn call(&mut self, function: Objects, arguments: Vec<Box<Objects>>, token: Token) -> Result<(), Box<Objects>> {
+    let file = self.frame().closure.function.file.clone();
+
+    match function {
+      Objects::CLOSURE(closure) => {
+        let mut locals: Vec<Cell> = Vec::with_capacity(closure.function.locals);
+        let arguments_length = arguments.len();
+
+        for argument in arguments.into_iter().take(closure.function.arguments) {
+          locals.push(new_cell(argument));
+        }
+
+        while locals.len() < closure.function.locals {
+          locals.push(new_cell(Null::new()));
+        }
+
+        self.frames.push(CallFrame {
+          closure,
+          ip: 0,
+          stack: self.stack.len(),
+          locals,
+          arguments: arguments_length,
+          handlers: Vec::new(),
+          call: Some(Frame::new(token.value.clone(), token, file)),
+        });
+
+        Ok(())
+      },
+
+      // Functions of the tree-walking evaluator, like the standard library ones.
+      Objects::ANONYMOUSFUNCTION(anonymous_function) => {
+        // The function runs in the machine environment, with the frames of the running calls.
+        let frames = self.get_running_frames();
+        let frames = std::mem::replace(&mut self.environment.frames, frames);
+        let running_file = std::mem::replace(&mut self.environment.running_file, file);
+
+        let object = call_anonymous_function(anonymous_function, arguments, token, self.environment);
+
+        self.environment.frames = frames;
+        self.environment.running_file = running_file;
+
+        if object.get_error().is_some() {
+          return Err(object);
+        }
+
+        self.stack.push(object);
+
+        Ok(())
+      },
+
+      // Create the instance and call the constructor with it.
+      Objects::CLASS(class) => match class.constructor.clone() {
+        Some(constructor) => {
+          let mut arguments = arguments;
+
+          arguments.insert(0, Instance::new(class));
+
+          self.call(Objects::CLOSURE(constructor), arguments, token)
+        },
+        None => Err(Error::new(format!("Unknown function: {}", token.value), token)),
+      },
+
+      Objects::BUILTIN(builtin) => match builtin.get_function() {
+        Some(function) => {
+          let object = (function)(token, arguments);
+
+          if object.get_error().is_some() {
+            return Err(object);
+          }
+
+          self.stack.push(object);
+
+          Ok(())
+        },
+        None => Err(Error::new(format!("Unknown function: {}", token.value), token)),
+      },
+
+      _ => Err(Error::new(format!("Unknown function: {}", token.value), token)),
+    }
+  }
+
+  /// Continue in the newest try of the error, or get the error when there is not one.
+  fn unwind(&mut self, error: Box<Objects>) -> Option<Box<Objects>> {
+    let mut error = error;
+
+    while let Some(frame) = self.frames.last_mut() {
+      if let Some(handler) = frame.handlers.pop() {
+        frame.ip = handler.target;
+
+        self.stack.truncate(handler.stack);
+        self.stack.push(error);
+
+        return None;
+      }
+
+      let frame = self.frames.pop().unwrap();
+
+      self.stack.truncate(frame.stack);
+
+      // Add the function call to the error trace.
+      if let (Some(call), Some(error_o)) = (frame.call, error.get_error()) {
+        error = error_o.add_frame(call, frame.closure.function.file.clone());
+      }
+    }
+
+    Some(error)
+  }
+
+  /// Run the main function of a file and get the result, or the uncaught error.
+  pub fn run(&mut self, function: Function) -> Box<Objects> {
+    let locals = (0..function.locals).map(|_| new_cell(Null::new())).collect();
+
+    self.frames.push(CallFrame {
+      closure: Closure {
+        function: Rc::new(function),
+        upvalues: Vec::new(),
+      },
+      ip: 0,
+      stack: 0,
+      locals,
+      arguments: 0,
+      handlers: Vec::new(),
+      call: None,
+    });
+
+    loop {
+      let function = self.frame().closure.function.clone();
+      let ip = self.frame().ip;
+
+      self.frame().ip += 1;
+
+      let token = || function.chunk.tokens[ip].clone();
+
+      let result: Result<(), Box<Objects>> = match &function.chunk.code[ip] {
+        Instructions::CONSTANT(index) => {
+          self.stack.push(function.chunk.constants[*index].clone());
+          Ok(())
+        },
+
+        Instructions::NULL => {
+          self.stack.push(Null::new());
+          Ok(())
+        },
+
+        Instructions::POP => {
+          self.pop();
+          Ok(())
+        },
+
+        Instructions::ARRAY(length) => {
+          let elements = self.stack.split_off(self.stack.len() - length);
+
+          self.stack.push(Array::new(elements));
+          Ok(())
+        },
+
+        Instructions::TEMPLATE(length) => {
+          let parts = self.stack.split_off(self.stack.len() - length);
+          let mut value = String::new();
+
+          for part in parts.iter() {
+            value.push_str(&part.string());
+          }
+
+          self.stack.push(StringO::new(value));
+          Ok(())
+        },
+
+        Instructions::ENUM(name, variants) => {
+          let length = variants.iter().filter(|(_, has_value)| *has_value).count();
+          let mut values = self.stack.split_off(self.stack.len() - length).into_iter();
+          let mut objects: Vec<Box<Objects>> = Vec::new();
+          let mut next_value: f64 = 0.0;
+
+          for (variant, has_value) in variants.iter() {
+            let value = if *has_value {
+              values.next().unwrap()
+            } else {
+              Number::new(next_value)
+            };
+
+            // The next variant without value continues from this one.
+            if let Some(number) = value.get_number() {
+              next_value = number.get_value() + 1.0;
+            }
+
+            objects.push(EnumVariant::new(name.clone(), variant.clone(), value));
+          }
+
+          self.stack.push(EnumO::new(name.clone(), objects));
+          Ok(())
+        },
+
+        Instructions::CLASS(name, methods) => {
+          let closures = self.stack.split_off(self.stack.len() - methods.len());
+          let constructor = self.pop().get_closure().unwrap();
+
+          let closures = methods.iter().cloned()
+            .zip(closures.iter().map(|closure| closure.get_closure().unwrap()))
+            .collect();
+
+          self.stack.push(Class::new_compiled(name.clone(), constructor, closures, function.file.clone()));
+          Ok(())
+        },
+
+        Instructions::HASHMAP(keys) => {
+          let values = self.stack.split_off(self.stack.len() - keys.len());
+          let mut data: Vec<HashItem> = Vec::new();
+
+          for (key, value) in keys.iter().zip(values) {
+            data.push(HashItem {
+              key: key.clone(),
+              value,
+            });
+          }
+
+          self.stack.push(HashMap::new(data));
+          Ok(())
+        },
+
+        Instructions::OPTIONALS(optionals) => {
+          let value = self.pop();
+
+          match value.get_hashmap() {
+            Some(hashmap) => self.stack.push(hashmap.with_optionals(optionals)),
+            None => self.stack.push(value),
+          }
+
+          Ok(())
+        },
+
+        Instructions::DEFINELOCAL(slot) => {
+          let value = self.pop();
+
+          self.frame().locals[*slot] = new_cell(value);
+          Ok(())
+        },
+
+        Instructions::GETLOCAL(slot) => {
+          let value = self.frame().locals[*slot].borrow().clone();
+
+          self.stack.push(value);
+          Ok(())
+        },
+
+        Instructions::SETLOCAL(slot) => {
+          let value = self.stack.last().unwrap().clone();
+
+          *self.frame().locals[*slot].borrow_mut() = value;
+          Ok(())
+        },
+
+        Instructions::GETUPVALUE(index) => {
+          let value = self.frame().closure.upvalues[*index].borrow().clone();
+
+          self.stack.push(value);
+          Ok(())
+        },
+
+        Instructions::SETUPVALUE(index) => {
+          let value = self.stack.last().unwrap().clone();
+
+          *self.frame().closure.upvalues[*index].borrow_mut() = value;
+          Ok(())
+        },
+
+        Instructions::GETGLOBAL(index) => {
+          let name = function.chunk.constants[*index].string();
+
+          let object = match self.environment.store.get_object(&name) {
+            Some(object) => object,
+            None => get_builtin_for_identifier(token()),
+          };
+
+          if object.get_error().is_some() {
+            Err(object)
+          } else {
+            self.stack.push(object);
+            Ok(())
+          }
+        },
+
+        Instructions::SETGLOBAL(index) => {
+          let name = function.chunk.constants[*index].string();
+          let value = self.stack.last().unwrap().clone();
+
+          match self.environment.store.get_const(&name) {
+            Some(declaration) => Err(const_error(token(), declaration)),
+            None => {
+              self.environment.store.update_object(name, value);
+              Ok(())
+            },
+          }
+        },
+
+        Instructions::DEFINEGLOBAL(index) => {
+          let name = function.chunk.constants[*index].string();
+          let value = self.pop();
+
+          self.environment.store.set_object(name, value);
+          Ok(())
+        },
+
+        Instructions::SETCONST(declaration) => Err(const_error(token(), declaration.clone())),
+
+        Instructions::BINARY(sign) => {
+          let right = self.pop();
+          let left = self.pop();
+
+          match binary(sign, left, right, token()) {
+            Ok(object) => {
+              self.stack.push(object);
+              Ok(())
+            },
+            Err(error) => Err(error),
+          }
+        },
+
+        Instructions::NOT => {
+          let value = self.pop();
+
+          self.stack.push(Boolean::new(value.expect_boolean(false) || value.get_null().is_some()));
+          Ok(())
+        },
+
+        Instructions::NEGATE => {
+          match self.pop().get_number() {
+            Some(number) => {
+              self.stack.push(Number::new(-number.get_value()));
+              Ok(())
+            },
+            None => Err(Error::new(
+              String::from("only can use the `-` prefix in number type."),
+              token(),
+            )),
+          }
+        },
+
+        Instructions::IS(right_type) => {
+          let value = self.pop();
+
+          // The identifiers are the enums and the classes.
+          let is_type = match right_type.token.get_type() {
+            Some(data_type) if !right_type.token.is_identifier() => object_is_type(&value, data_type),
+            _ => match value.as_ref() {
+              Objects::ENUMVARIANT(variant) => variant.enum_name == right_type.value,
+              Objects::INSTANCE(instance) => instance.class.name == right_type.value,
+              _ => false,
+            },
+          };
+
+          self.stack.push(Boolean::new(is_type));
+          Ok(())
+        },
+
+        Instructions::AND(target) => {
+          if self.stack.last().unwrap().expect_boolean(false) {
+            self.frame().ip = *target;
+          }
+
+          Ok(())
+        },
+
+        Instructions::OR(target) => {
+          if self.stack.last().unwrap().expect_boolean(true) {
+            self.frame().ip = *target;
+          }
+
+          Ok(())
+        },
+
+        Instructions::INDEX => {
+          let index = self.pop();
+          let left = self.pop();
+
+          match left.as_ref() {
+            Objects::STRING(string) if index.get_number().is_some() => {
+              let characters: Vec<char> = string.get_value().chars().collect();
+
+              self.stack.push(match get_index(&index, characters.len()).and_then(|index| characters.get(index)) {
+                Some(character) => StringO::new(character.to_string()),
+                None => Null::new(),
+              });
+              Ok(())
+            },
+            Objects::ARRAY(array) if index.get_number().is_some() => {
+              self.stack.push(match get_index(&index, array.get_length()).and_then(|index| array.get_element(index)) {
+                Some(element) => element,
+                None => Null::new(),
+              });
+              Ok(())
+            },
+            _ => Err(Error::new(String::from("is not a valid expression."), token())),
+          }
+        },
+
+        Instructions::SETINDEX => {
+          let value = self.pop();
+          let index = self.pop();
+          let left = self.pop();
+
+          match left.get_array() {
+            Some(array) => {
+              let mut elements = array.get_elements();
+
+              match get_index(&index, elements.len()) {
+                Some(position) if position < elements.len() => {
+                  elements[position] = value;
+
+                  self.stack.push(Array::new(elements));
+                  Ok(())
+                },
+                // Throw a catchable error when the index is out of the array.
+                _ => Err(Error::new(
+                  format!("`{}` is out of the array range.", index.string()),
+                  token(),
+                )),
+              }
+            },
+            None => Err(unknown_infix(&left, &value, token())),
+          }
+        },
+
+        Instructions::MEMBER(index) => {
+          let name = function.chunk.constants[*index].string();
+          let left = self.pop();
+
+          let object = self.get_member(&left, &name, token());
+
+          if object.get_error().is_some() {
+            Err(object)
+          } else {
+            self.stack.push(object);
+            Ok(())
+          }
+        },
+
+        Instructions::SETMEMBER(index) => {
+          let name = function.chunk.constants[*index].string();
+          let left = self.pop();
+          let value = self.stack.last().unwrap().clone();
+
+          match left.get_instance() {
+            Some(instance) => {
+              instance.set_field(name, value);
+              Ok(())
+            },
+            None => Err(unknown_infix(&left, &value, token())),
+          }
+        },
+
+        Instructions::METHOD(index, length) => {
+          let name = function.chunk.constants[*index].string();
+          let arguments = self.stack.split_off(self.stack.len() - length);
+          let left = self.pop();
+
+          match get_builtin_member(&left, &name) {
+            // Call the native method of the value, like the string `split()`.
+            Some(Members::METHOD(method)) => {
+              let object = (method)(token(), &left, arguments);
+
+              if object.get_error().is_some() {
+                Err(object)
+              } else {
+                self.stack.push(object);
+                Ok(())
+              }
+            },
+            _ => match left.get_instance().and_then(|instance| instance.class.get_closure(&name)) {
+              // Call the class method with the instance as `this`.
+              Some(closure) => {
+                let mut arguments = arguments;
+
+                arguments.insert(0, left);
+
+                self.call(Objects::CLOSURE(closure), arguments, token())
+              },
+              None => {
+                let method = self.get_member(&left, &name, token());
+
+                if method.get_error().is_some() {
+                  Err(method)
+                } else {
+                  self.call(*method, arguments, token())
+                }
+              },
+            },
+          }
+        },
+
+        Instructions::JUMP(target) => {
+          self.frame().ip = *target;
+          Ok(())
+        },
+
+        Instructions::JUMPIFFALSE(target) => {
+          if !Boolean::is_truthy(self.pop()) {
+            self.frame().ip = *target;
+          }
+
+          Ok(())
+        },
+
+        Instructions::JUMPIFARGUMENT(index, target) => {
+          if self.frame().arguments > *index {
+            self.frame().ip = *target;
+          }
+
+          Ok(())
+        },
+
+        Instructions::ITERATOR(is_of) => {
+          match (is_of, self.stack.last().unwrap().as_ref()) {
+            (false, Objects::ARRAY(_)) | (true, Objects::HASHMAP(_)) => Ok(()),
+            // Iterate the enum variants.
+            (false, Objects::ENUM(enum_o)) => {
+              let variants = enum_o.get_variants();
+
+              *self.stack.last_mut().unwrap() = Array::new(variants);
+              Ok(())
+            },
+            (false, _) => Err(Error::new(String::from("expect an array or an enum expression."), token())),
+            (true, _) => Err(Error::new(String::from("expect an hashmap expression."), token())),
+          }
+        },
+
+        Instructions::NEXT(collection, index, target) => {
+          let collection = self.frame().locals[*collection].clone();
+          let index_cell = self.frame().locals[*index].clone();
+          let position = index_cell.borrow().get_number().unwrap().get_value() as usize;
+
+          let mut finished = true;
+
+          match collection.borrow().as_ref() {
+            Objects::ARRAY(array) => {
+              if let Some(element) = array.get_element(position) {
+                self.stack.push(element);
+                finished = false;
+              }
+            },
+            Objects::HASHMAP(hashmap) => {
+              if let Some(item) = hashmap.get_item(position) {
+                self.stack.push(StringO::new(item.key));
+                self.stack.push(item.value);
+                finished = false;
+              }
+            },
+            _ => {},
+          }
+
+          if finished {
+            self.frame().ip = *target;
+          } else {
+            *index_cell.borrow_mut() = Number::new((position + 1) as f64);
+          }
+
+          Ok(())
+        },
+
+        Instructions::CLOSURE(index, captures) => {
+          let frame = self.frame();
+          let mut upvalues: Vec<Cell> = Vec::new();
+
+          for capture in captures.iter() {
+            upvalues.push(match capture {
+              Capture::LOCAL(slot) => frame.locals[*slot].clone(),
+              Capture::UPVALUE(index) => frame.closure.upvalues[*index].clone(),
+            });
+          }
+
+          let closure = Closure::new(function.chunk.functions[*index].clone(), upvalues);
+
+          self.stack.push(closure);
+          Ok(())
+        },
+
+        Instructions::CALL(length) => {
+          let arguments = self.stack.split_off(self.stack.len() - length);
+          let callee = self.pop();
+
+          self.call(*callee, arguments, token())
+        },
+
+        Instructions::RETURN => {
+          let value = self.pop();
+          let frame = self.frames.pop().unwrap();
+
+          self.stack.truncate(frame.stack);
+
+          if self.frames.is_empty() {
+            return value;
+          }
+
+          self.stack.push(value);
+          Ok(())
+        },
+
+        Instructions::IMPORT(import) => match import::evaluate(import.clone(), self.environment) {
+          Some(error) => Err(error),
+          None => Ok(()),
+        },
+
+        Instructions::THROW => {
+          let value = self.pop();
+
+          // Throw again the error of a finally.
+          if value.get_error().is_some() {
+            Err(value)
+          } else {
+            Err(Error::from_object(&value, token()))
+          }
+        },
+
+        Instructions::CATCH => {
+          let value = self.pop();
+
+          let object = match value.get_error() {
+            Some(error) => error.get_object(function.file.clone(), self.get_running_frames()),
+            None => value,
+          };
+
+          self.stack.push(object);
+          Ok(())
+        },
+
+        Instructions::PUSHHANDLER(target) => {
+          let stack = self.stack.len();
+
+          self.frame().handlers.push(Handler {
+            target: *target,
+            stack,
+          });
+          Ok(())
+        },
+
+        Instructions::POPHANDLER => {
+          self.frame().handlers.pop();
+          Ok(())
+        },
+      };
+
+      if let Err(error) = result {
+        if let Some(error) = self.unwind(error) {
+          return error;
+        }
+      }
+    }
+  }
+}
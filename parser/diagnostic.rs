@@ -0,0 +1,151 @@
+use super::{
+  Error,
+  tokens::Token,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Codes {
+  // Generic codes for each phase.
+  SYNTAX,
+  TYPE,
+  RUNTIME,
+  FILE,
+
+  // Type errors.
+  IDENTIFIERNOTFOUND,
+  ALREADYINUSE,
+  NOTSATISFIED,
+  POSSIBLYNULL,
+  GENERICNOTINFERRED,
+  ARGUMENTS,
+  CONSTASSIGNMENT,
+
+  // Warnings.
+  UNREACHABLECASE,
+  NOBYTECODE,
+  NONEXHAUSTIVE,
+}
+
+impl Codes {
+  /// Get the stable code, like `E0001`.
+  pub fn get_code(&self) -> String {
+    String::from(match self {
+      Codes::SYNTAX => "E0001",
+      Codes::TYPE => "E0002",
+      Codes::RUNTIME => "E0003",
+      Codes::FILE => "E0004",
+
+      Codes::IDENTIFIERNOTFOUND => "E0010",
+      Codes::ALREADYINUSE => "E0011",
+      Codes::NOTSATISFIED => "E0012",
+      Codes::POSSIBLYNULL => "E0013",
+      Codes::GENERICNOTINFERRED => "E0014",
+      Codes::ARGUMENTS => "E0015",
+      Codes::CONSTASSIGNMENT => "E0016",
+
+      Codes::UNREACHABLECASE => "W0001",
+      Codes::NOBYTECODE => "W0002",
+      Codes::NONEXHAUSTIVE => "W0003",
+    })
+  }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Severity {
+  ERROR,
+  WARNING,
+  NOTE,
+}
+
+impl Severity {
+  pub fn string(&self) -> String {
+    String::from(match self {
+      Severity::ERROR => "error",
+      Severity::WARNING => "warning",
+      Severity::NOTE => "note",
+    })
+  }
+}
+
+/// A secondary span with a message.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Label {
+  pub message: String,
+  pub line: usize,
+  pub start_position: usize,
+  pub end_position: usize,
+}
+
+impl Label {
+  pub fn from_token(message: String, token: Token) -> Label {
+    Label {
+      message,
+      line: token.line,
+      start_position: token.position,
+      end_position: token.position + token.value.chars().count(),
+    }
+  }
+}
+
+/// A function call of a runtime error trace.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TraceFrame {
+  pub name: String,
+  pub file: String,
+  pub line: usize,
+  pub start_position: usize,
+  pub end_position: usize,
+}
+
+impl TraceFrame {
+  pub fn from_token(name: String, file: String, token: Token) -> TraceFrame {
+    TraceFrame {
+      name,
+      file,
+      line: token.line,
+      start_position: token.position,
+      end_position: token.position + token.value.chars().count(),
+    }
+  }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+  pub code: Codes,
+  pub severity: Severity,
+  pub message: String,
+
+  // Primary span.
+  pub line: usize,
+  pub start_position: usize,
+  pub end_position: usize,
+
+  pub labels: Vec<Label>,
+  pub help: Option<String>,
+
+  // File of the primary span, when it is not the rendered file.
+  pub file: Option<String>,
+  // Function calls from the innermost, for runtime errors.
+  pub trace: Vec<TraceFrame>,
+}
+
+impl Diagnostic {
+  /// Create a diagnostic from an error, using the default code when the error does not have one.
+  pub fn from_error(severity: Severity, error: Error, default_code: Codes) -> Diagnostic {
+    Diagnostic {
+      code: error.code.unwrap_or(default_code),
+      severity,
+      message: error.message,
+
+      line: error.line,
+      start_position: error.start_position,
+      end_position: error.end_position,
+
+      labels: error.labels,
+      help: error.help,
+
+      file: None,
+      trace: Vec::new(),
+    }
+  }
+}
@@ -1,33 +1,58 @@
-use super::tokens::Token;
+use super::{
+  Codes,
+  Label,
+  tokens::Token,
+};
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Error {
   pub message: String,
   pub line: usize,
   pub start_position: usize,
   pub end_position: usize,
+
+  pub code: Option<Codes>,
+  pub labels: Vec<Label>,
+  pub help: Option<String>,
 }
 
 impl Error {
   pub fn new(message: String, line: usize, start_position: usize, end_position: usize) -> Error {
-    Error { message, line, start_position, end_position }
+    Error {
+      message,
+      line,
+      start_position,
+      end_position,
+
+      code: None,
+      labels: Vec::new(),
+      help: None,
+    }
   }
 
   pub fn new_empty() -> Error {
-    Error {
-      message: String::new(),
-      line: 0,
-      start_position: 0,
-      end_position: 0,
-    }
+    Error::new(String::new(), 0, 0, 0)
   }
 
   pub fn from_token(message: String, token: Token) -> Error {
-    Error {
-      message,
-      line: token.line,
-      start_position: token.position,
-      end_position: token.position + token.value.len(),
-    }
+    Error::new(message, token.line, token.position, token.position + token.value.chars().count())
+  }
+
+  /// Set the diagnostic code of the error.
+  pub fn with_code(mut self, code: Codes) -> Error {
+    self.code = Some(code);
+    self
+  }
+
+  /// Add a secondary span to the error.
+  pub fn with_label(mut self, message: String, token: Token) -> Error {
+    self.labels.push(Label::from_token(message, token));
+    self
+  }
+
+  /// Set the help text of the error.
+  pub fn with_help(mut self, help: String) -> Error {
+    self.help = Some(help);
+    self
   }
 }
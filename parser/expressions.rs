@@ -13,6 +13,7 @@ mod number;
 mod prefix;
 mod string;
 mod suffix;
+mod template;
 mod types;
 
 pub use anonymous_function::*;
@@ -30,6 +31,7 @@ pub use number::*;
 pub use prefix::*;
 pub use string::*;
 pub use suffix::*;
+pub use template::*;
 pub use types::*;
 
 use super::{
@@ -63,6 +65,18 @@ pub fn parse_expression<'a>(
     expression = Ok(Identifier::new_box_from_token(current_token.clone()));
   }
 
+  // Parse this.
+  if current_token.token.expect_keyword(&Keywords::THIS) {
+    expression = if with_this {
+      Ok(Identifier::new_box_from_token(current_token.clone()))
+    } else {
+      Err(Error::from_token(
+        String::from("`this` only can be used inside a class."),
+        current_token.clone(),
+      ))
+    };
+  }
+
   // Parse nulls.
   if current_token.token.expect_type(&Types::NULL) {
     expression = Ok(Null::new_box_from_token(current_token.clone()));
@@ -73,6 +87,11 @@ pub fn parse_expression<'a>(
     expression = Ok(StringE::new_box_from_token(current_token.clone()));
   }
 
+  // Parse template strings.
+  if current_token.token.is_template() {
+    expression = Template::parse(parser, standard_library, with_this);
+  }
+
   // Parse numbers.
   if current_token.token.is_number() {
     expression = Number::parse(parser);
@@ -98,6 +117,9 @@ pub fn parse_expression<'a>(
       parser.get_next_token().token.is_identifier() ||
       parser.get_next_token().token.expect_sign(&Signs::RIGHTPARENTHESES)
     )
+  ) || (
+    current_token.token.expect_sign(&Signs::LESSTHAN) &&
+    parser.get_next_token().token.is_identifier()
   ) {
     expression = AnonymousFunction::parse(parser, standard_library, with_this);
   }
@@ -133,8 +155,10 @@ pub fn parse_expression<'a>(
     return Err(error);
   }
 
-  // Parse infix expression.
-  while !parser.next_token_is(Signs::new(Signs::SEMICOLON)) &&
+  // Parse infix expression, until the `;` that ends the statement. The calls and the assignments
+  // get their `;`, so the current token can be it.
+  while !parser.current_token_is(Signs::new(Signs::SEMICOLON)) &&
+    !parser.next_token_is(Signs::new(Signs::SEMICOLON)) &&
     precedence < parser.next_precedence()
   {
     // Parse Infix, Alias and method.
@@ -162,7 +186,8 @@ pub fn parse_expression<'a>(
       parser.next_token_is(Signs::new(Signs::PLUSASSIGN)) ||
       parser.next_token_is(Signs::new(Signs::MINUSASSIGN)) ||
       parser.next_token_is(Signs::new(Signs::MULTIPLYASSIGN)) ||
-      parser.next_token_is(Signs::new(Signs::DIVIDEASSIGN)) {
+      parser.next_token_is(Signs::new(Signs::DIVIDEASSIGN)) ||
+      parser.next_token_is(Signs::new(Signs::MODULEASSIGN)) {
       // Get the next token.
       parser.next_token();
 
@@ -14,12 +14,14 @@ use super::{
   Argument,
   Expression,
   Expressions,
+  parse_generics,
   parse_type,
 };
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct AnonymousFunction {
   token: Token,
+  generics: Vec<Token>,
   arguments: Vec<Box<Expressions>>,
   data_type: Token,
   body: Box<Statements>,
@@ -29,6 +31,7 @@ impl Expression for AnonymousFunction {
   fn new() -> Self {
     Self {
       token: Token::new_empty(),
+      generics: Vec::new(),
       arguments: Vec::new(),
       data_type: Token::from_value("any", 0, 0),
       body: Block::new_box(),
@@ -48,14 +51,20 @@ impl Expression for AnonymousFunction {
   }
 
   fn string(&self) -> String {
+    let mut generics: Vec<String> = Vec::new();
     let mut arguments: Vec<String> = Vec::new();
 
+    for generic in self.get_generics().iter() {
+      generics.push(generic.value.clone());
+    }
+
     for argument in self.get_arguments().iter() {
       arguments.push(argument.string());
     }
 
     let function = format!(
-      "({}): {}",
+      "{}({}): {}",
+      if generics.is_empty() { String::new() } else { format!("<{}>", generics.join(", ")) },
       arguments.join(", "),
       self.get_type().value,
     );
@@ -71,6 +80,10 @@ impl Expression for AnonymousFunction {
 }
 
 impl AnonymousFunction {
+  pub fn get_generics(&self) -> Vec<Token> {
+    self.generics.clone()
+  }
+
   pub fn get_arguments(&self) -> Vec<Box<Expressions>> {
     self.arguments.clone()
   }
@@ -90,6 +103,37 @@ impl AnonymousFunction {
   ) -> Result<Box<Expressions>, Error> {
     let mut function: AnonymousFunction = Expression::from_token(parser.get_current_token());
 
+    // Check if the current token is a less than.
+    if parser.current_token_is(Signs::new(Signs::LESSTHAN)) {
+      // Parse generics.
+      match parse_generics(parser) {
+        Ok(generics) => {
+          function.generics = generics;
+        },
+        Err(error) => {
+          return Err(error);
+        },
+      }
+
+      // Check if the next token is a left parentheses.
+      if !parser.expect_token(Signs::new(Signs::LEFTPARENTHESES)) {
+        return Err(Error::from_token(
+          format!("expect `(`, got `{}` instead.", parser.get_next_token().value),
+          parser.get_next_token(),
+        ));
+      }
+    }
+
+    let outer_generics: Vec<String> = parser.get_generics();
+    let mut generics: Vec<String> = outer_generics.clone();
+
+    for generic in function.generics.iter() {
+      generics.push(generic.value.clone());
+    }
+
+    // Set the generics for the function data types.
+    parser.set_generics(generics);
+
     // Check if the current token is a left parentheses.
     if !parser.current_token_is(Signs::new(Signs::LEFTPARENTHESES)) {
       // Get the next token.
@@ -135,8 +179,8 @@ impl AnonymousFunction {
       parser.next_token();
     }
 
-    // Check if the function token is a left parentheses.
-    if function.token.token.expect_sign(&Signs::LEFTPARENTHESES) {
+    // Check if the function is an arrow function.
+    if !function.token.token.expect_keyword(&Keywords::FUNCTION) {
       // Check if the next token is an assign arrow sign.
       if !parser.current_token_is(Signs::new(Signs::ASSIGNARROW)) {
         return Err(Error::from_token(
@@ -167,6 +211,9 @@ impl AnonymousFunction {
       },
     }
 
+    // Restore the outer generics.
+    parser.set_generics(outer_generics);
+
     Ok(Box::new(Expressions::ANONYMOUSFUNCTION(function)))
   }
 }
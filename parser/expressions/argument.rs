@@ -3,6 +3,7 @@ use crate::{
   Parser,
   Precedence,
   tokens::{
+    Keywords,
     Signs,
     Token,
     Tokens,
@@ -20,7 +21,8 @@ use super::{
 pub struct Argument {
   token: Token,
   data_type: Token,
-  value: Option<Box<Expressions>>
+  value: Option<Box<Expressions>>,
+  is_const: bool,
 }
 
 impl Expression for Argument {
@@ -29,6 +31,7 @@ impl Expression for Argument {
       token: Token::new_empty(),
       data_type: Token::new_empty(),
       value: None,
+      is_const: false,
     }
   }
 
@@ -37,6 +40,7 @@ impl Expression for Argument {
       token,
       data_type: Token::new_empty(),
       value: None,
+      is_const: false,
     }
   }
 
@@ -68,7 +72,7 @@ impl Argument {
   }
 
   pub fn new_box_full(token: Token, data_type: Token, value: Option<Box<Expressions>>) -> Box<Expressions> {
-    Box::new(Expressions::ARGUMENT(Argument { token, data_type, value }))
+    Box::new(Expressions::ARGUMENT(Argument { token, data_type, value, is_const: false }))
   }
 
   pub fn get_type(&self) -> Token {
@@ -79,6 +83,10 @@ impl Argument {
     self.value.clone()
   }
 
+  pub fn is_const(&self) -> bool {
+    self.is_const
+  }
+
   pub fn parse<'a>(
     parser: &'a mut Parser,
     standard_library: bool,
@@ -94,6 +102,14 @@ impl Argument {
 
     let mut has_default = false;
     while !parser.current_token_is(Signs::new(Signs::RIGHTPARENTHESES)) {
+      // Check if the argument is a const.
+      let is_const = parser.next_token_is(Keywords::new(Keywords::CONST));
+
+      if is_const {
+        // Get the next token.
+        parser.next_token();
+      }
+
       // Check if the next token is an identifier.
       if !parser.expect_token(Box::new(Tokens::IDENTIFIER)) {
         return Err(Error::from_token(
@@ -104,6 +120,8 @@ impl Argument {
 
       let mut argument: Argument = Expression::from_token(parser.get_current_token());
 
+      argument.is_const = is_const;
+
       // Check if the next token is a colon.
       if !parser.expect_token(Signs::new(Signs::COLON)) {
         return Err(Error::from_token(
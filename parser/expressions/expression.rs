@@ -33,6 +33,7 @@ pub enum Expressions {
   PREFIX(Prefix),
   STRING(StringE),
   SUFFIX(Suffix),
+  TEMPLATE(Template),
 }
 
 impl Expressions {
@@ -140,6 +141,13 @@ impl Expressions {
     }
   }
 
+  pub fn get_template(&self) -> Option<Template> {
+    match self {
+      Expressions::TEMPLATE(template) => Some(template.clone()),
+      _ => None,
+    }
+  }
+
   pub fn token(&self) -> Token {
     match self {
       Expressions::ANONYMOUSFUNCTION(anonymous_function) => anonymous_function.get_token(),
@@ -157,6 +165,7 @@ impl Expressions {
       Expressions::PREFIX(prefix) => prefix.get_token(),
       Expressions::STRING(string) => string.get_token(),
       Expressions::SUFFIX(suffix) => suffix.get_token(),
+      Expressions::TEMPLATE(template) => template.get_token(),
     }
   }
 
@@ -177,6 +186,7 @@ impl Expressions {
       Expressions::PREFIX(prefix) => prefix.string(),
       Expressions::STRING(string) => string.string(),
       Expressions::SUFFIX(suffix) => suffix.string(),
+      Expressions::TEMPLATE(template) => template.string(),
     }
   }
 }
@@ -140,7 +140,8 @@ impl Infix {
       parser.current_token_is(Signs::new(Signs::PLUSASSIGN)) ||
       parser.current_token_is(Signs::new(Signs::MINUSASSIGN)) ||
       parser.current_token_is(Signs::new(Signs::MULTIPLYASSIGN)) ||
-      parser.current_token_is(Signs::new(Signs::DIVIDEASSIGN)) {
+      parser.current_token_is(Signs::new(Signs::DIVIDEASSIGN)) ||
+      parser.current_token_is(Signs::new(Signs::MODULEASSIGN)) {
       infix.itype = InfixType::VARIABLESET;
     }
 
@@ -47,7 +47,7 @@ impl Number {
   pub fn new_box_from_token(token: Token) -> Box<Expressions> {
     let mut number: Number = Expression::from_token(token.clone());
 
-    if let Ok(value) = token.value.parse::<f64>() {
+    if let Some(value) = Number::parse_value(&token.value) {
       number.value = value;
     }
 
@@ -58,15 +58,49 @@ impl Number {
     self.value.clone()
   }
 
+  /// Get the value of a number literal, with the `_` separators and the `0x`, `0b` and `0o`
+  /// prefixes.
+  pub fn parse_value(value: &str) -> Option<f64> {
+    let value = value.replace('_', "");
+
+    let radix: u32 = match value.get(..2) {
+      Some("0x") | Some("0X") => 16,
+      Some("0b") | Some("0B") => 2,
+      Some("0o") | Some("0O") => 8,
+      _ => {
+        return value.parse::<f64>().ok();
+      },
+    };
+
+    // Check if the number only has the prefix.
+    if value.len() == 2 {
+      return None;
+    }
+
+    // The digits are added as a float, so the big numbers do not overflow.
+    let mut number: f64 = 0.0;
+
+    for character in value[2..].chars() {
+      match character.to_digit(radix) {
+        Some(digit) => number = number * radix as f64 + digit as f64,
+        None => {
+          return None;
+        },
+      }
+    }
+
+    Some(number)
+  }
+
   pub fn parse<'a>(parser: &'a mut Parser) -> Result<Box<Expressions>, Error> {
     let mut number: Number = Expression::from_token(parser.get_current_token());
 
-    match parser.get_current_token().value.parse::<f64>() {
-      Ok(value) => {
+    match Number::parse_value(&parser.get_current_token().value) {
+      Some(value) => {
         number.value = value;
         Ok(Box::new(Expressions::NUMBER(number)))
       },
-      Err(_) => Err(Error::from_token(
+      None => Err(Error::from_token(
         format!("could not parse `{}` as integer.", parser.get_current_token().value),
         parser.get_current_token(),
       )),
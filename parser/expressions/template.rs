@@ -0,0 +1,128 @@
+use crate::{
+  Error,
+  Parser,
+  Precedence,
+  tokens::{
+    Token,
+    Tokens,
+  },
+};
+
+use super::{
+  Expression,
+  Expressions,
+  StringE,
+  parse_expression,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Template {
+  token: Token,
+  parts: Vec<Expressions>,
+}
+
+impl Expression for Template {
+  fn new() -> Self {
+    Self {
+      token: Token::new_empty(),
+      parts: Vec::new(),
+    }
+  }
+
+  fn from_token(token: Token) -> Self {
+    let mut template: Self = Expression::new();
+
+    template.token = token;
+
+    template
+  }
+
+  fn get_token(&self) -> Token {
+    self.token.clone()
+  }
+
+  fn string(&self) -> String {
+    let mut value = String::new();
+
+    for part in self.get_parts().iter() {
+      match part.get_string() {
+        Some(string) => {
+          let string = string.get_value();
+
+          value.push_str(&string[1..string.len() - 1]);
+        },
+        None => value.push_str(&format!("${{{}}}", part.string())),
+      }
+    }
+
+    format!("`{}`", value)
+  }
+}
+
+impl Template {
+  /// Get the parts of the template, the texts as strings and the expressions between them.
+  pub fn get_parts(&self) -> Vec<Box<Expressions>> {
+    self.parts.iter().cloned().map(Box::new).collect()
+  }
+
+  pub fn parse(
+    parser: &mut Parser,
+    standard_library: bool,
+    with_this: bool,
+  ) -> Result<Box<Expressions>, Error> {
+    let mut template: Template = Expression::from_token(parser.get_current_token());
+
+    loop {
+      let token: Token = parser.get_current_token();
+
+      // Check if an expression follows the text (`${`).
+      let has_expression = token.value.ends_with("${");
+      let end = token.value.len() - if has_expression { 2 } else { 1 };
+
+      template.parts.push(*StringE::new_box_from_token(Token::new(
+        Box::new(Tokens::STRING),
+        format!("'{}'", &token.value[1..end]),
+        token.line,
+        token.position,
+      )));
+
+      if !has_expression {
+        break;
+      }
+
+      // Get the next token.
+      parser.next_token();
+
+      // Check if the expression is empty (`${}`). The rest of the template starts with the `}`,
+      // and a nested template with a backtick.
+      if parser.current_token_is(Box::new(Tokens::TEMPLATE)) &&
+        parser.get_current_token().value.starts_with('}') {
+        return Err(Error::from_token(
+          String::from("the template expression is empty."),
+          parser.get_current_token(),
+        ));
+      }
+
+      // Parse expression.
+      match parse_expression(parser, Precedence::LOWEST, standard_library, with_this) {
+        Ok(expression) => {
+          template.parts.push(*expression);
+        },
+        Err(error) => {
+          return Err(error);
+        },
+      }
+
+      // Check if the next token is the rest of the template.
+      if !parser.expect_token(Box::new(Tokens::TEMPLATE)) {
+        return Err(Error::from_token(
+          format!("expect `}}`, got `{}` instead.", parser.get_next_token().value),
+          parser.get_next_token(),
+        ));
+      }
+    }
+
+    // Return the template expression.
+    Ok(Box::new(Expressions::TEMPLATE(template)))
+  }
+}
@@ -1,9 +1,121 @@
-use crate::Parser;
-use crate::tokens::*;
+use crate::{
+  Error,
+  Parser,
+  tokens::*,
+};
+
+/// Parse the generic names (`<T, U>`), the current token must be a `<`.
+pub fn parse_generics(parser: &mut Parser) -> Result<Vec<Token>, Error> {
+  let mut generics: Vec<Token> = Vec::new();
+
+  while !parser.next_token_is(Signs::new(Signs::GREATERTHAN)) {
+    // Check if the next token is an identifier.
+    if !parser.expect_token(Box::new(Tokens::IDENTIFIER)) {
+      return Err(Error::from_token(
+        format!("`{}` is not a valid generic name.", parser.get_next_token().value),
+        parser.get_next_token(),
+      ));
+    }
+
+    // Check if the generic name is already in use.
+    for generic in generics.iter() {
+      if generic.value == parser.get_current_token().value {
+        return Err(Error::from_token(
+          format!("`{}` generic is duplicated.", generic.value),
+          parser.get_current_token(),
+        ));
+      }
+    }
+
+    generics.push(parser.get_current_token());
+
+    // Check if the next token is a comma.
+    if !parser.expect_token(Signs::new(Signs::COMMA)) {
+      break;
+    }
+  }
+
+  // Check if the next token is a greater than.
+  if !parser.expect_token(Signs::new(Signs::GREATERTHAN)) {
+    return Err(Error::from_token(
+      format!("expect `>`, got `{}` instead.", parser.get_next_token().value),
+      parser.get_next_token(),
+    ));
+  }
+
+  if generics.is_empty() {
+    return Err(Error::from_token(
+      String::from("expect at least one generic name."),
+      parser.get_current_token(),
+    ));
+  }
+
+  Ok(generics)
+}
+
+/// Parse the generic arguments of an identifier data type (`Box<number>`).
+fn parse_generic_arguments(parser: &mut Parser, token: Token) -> Result<Token, ()> {
+  let mut arguments: Vec<String> = Vec::new();
+
+  // Get the next token.
+  parser.next_token();
+
+  while !parser.next_token_is(Signs::new(Signs::GREATERTHAN)) {
+    // Get the next token.
+    parser.next_token();
+
+    // Parse type.
+    match parse_type(parser) {
+      Ok(data_type) => {
+        arguments.push(data_type.value);
+      },
+      Err(_) => {
+        return Err(());
+      },
+    }
+
+    // Check if the next token is a comma.
+    if !parser.expect_token(Signs::new(Signs::COMMA)) {
+      break;
+    }
+  }
+
+  // Check if the next token is a greater than.
+  if !parser.expect_token(Signs::new(Signs::GREATERTHAN)) {
+    return Err(());
+  }
+
+  Ok(Token::new(
+    Box::new(Tokens::IDENTIFIER),
+    format!("{}<{}>", token.value, arguments.join(", ")),
+    token.line,
+    token.position,
+  ))
+}
 
 pub fn parse_type<'a>(parser: &'a mut Parser) -> Result<Token, ()> {
   let token: Token =
-    if parser.get_current_token().token.get_type().is_some() ||
+    if parser.get_current_token().token.is_identifier() &&
+      parser.get_generics().contains(&parser.get_current_token().value) {
+      // Parse generic names.
+      let current_token = parser.get_current_token();
+
+      Token::new(
+        Box::new(Tokens::TYPE(Types::GENERIC(current_token.value.clone()))),
+        current_token.value,
+        current_token.line,
+        current_token.position,
+      )
+    } else if parser.get_current_token().token.is_identifier() &&
+      parser.next_token_is(Signs::new(Signs::LESSTHAN)) {
+      // Parse identifiers with generic arguments.
+      match parse_generic_arguments(parser, parser.get_current_token()) {
+        Ok(token) => token,
+        Err(_) => {
+          return Err(());
+        },
+      }
+    } else if parser.get_current_token().token.get_type().is_some() ||
       parser.get_current_token().token.is_identifier() {
       parser.get_current_token()
     } else {
@@ -13,13 +125,38 @@ pub fn parse_type<'a>(parser: &'a mut Parser) -> Result<Token, ()> {
       }
     };
 
+  if token.token.get_type().is_none() && !token.token.is_identifier() {
+    return Err(());
+  }
+
   // Parse arrays.
-  if token.token.get_type().is_some() || token.token.is_identifier() {
-    match Array::parse(parser, token.clone()) {
-      Ok(token) => Ok(token),
-      Err(_) => Ok(token),
-    }
+  let token: Token = match Array::parse(parser, token.clone()) {
+    Ok(token) => token,
+    Err(_) => token,
+  };
+
+  // Parse optional types.
+  let token: Token = if parser.expect_token(Signs::new(Signs::QUESTION)) {
+    let union = Union::new(vec![
+      token.clone(),
+      Token::from_value("null", token.line, token.position),
+    ]);
+    let value = union.get_value();
+
+    Token::new(
+      Box::new(Tokens::TYPE(Types::UNION(union))),
+      value,
+      token.line,
+      token.position,
+    )
   } else {
-    Err(())
+    token
+  };
+
+  // Parse unions.
+  match Union::parse(parser, token.clone()) {
+    Ok(token) => Ok(token),
+    Err(0) => Ok(token),
+    Err(_) => Err(()),
   }
 }
@@ -3,31 +3,60 @@ mod utils;
 
 pub use file::File;
 
-use super::tokens::*;
+use super::{
+  Error,
+  tokens::*,
+};
+
+use std::collections::HashMap;
 
 #[derive(Debug)]
 pub struct Lexer {
   pub file: File,
+  characters: Vec<char>,
 
   pub current_line: usize,
   pub current_line_position: usize,
   pub current_position: usize,
-  pub current_character: u8,
+  pub current_character: char,
 
   pub next_position: usize,
+
+  // Depth of the open braces, and the depth of each open template expression (`${`).
+  braces: usize,
+  templates: Vec<usize>,
+
+  // Doc comments of the next token, and the doc comments by the line and the position of
+  // their token.
+  docs: Vec<String>,
+  documentation: HashMap<(usize, usize), String>,
+
+  // Errors of the tokens, like an unknown escape in a string.
+  pub errors: Vec<Error>,
 }
 
 impl Lexer {
   pub fn new(file: File) -> Lexer {
+    let characters: Vec<char> = file.content.chars().collect();
+
     let mut lexer = Lexer {
       file,
-      
+      characters,
+
       current_line: 1,
       current_line_position: 0,
       current_position: 0,
-      current_character: 0,
+      current_character: '\0',
 
       next_position: 0,
+
+      braces: 0,
+      templates: Vec::new(),
+
+      docs: Vec::new(),
+      documentation: HashMap::new(),
+
+      errors: Vec::new(),
     };
 
     lexer.read_next_character();
@@ -36,47 +65,47 @@ impl Lexer {
   }
 
   /// Read the next character and move the cursor to the next position.
+  /// The positions are counted in characters, so the columns are right with any character.
   fn read_next_character(&mut self) {
     self.current_position = self.next_position;
     self.next_position += 1;
     self.current_line_position += 1;
 
-    if self.current_position >= self.file.content.len() {
-      self.current_character = 0;
-    } else {
-      self.current_character = self.file.content.as_bytes()[self.current_position];
+    self.current_character = self.get_character(self.current_position);
+  }
+
+  /// Get the character of the position, or `\0` at the end of the file.
+  fn get_character(&self, position: usize) -> char {
+    match self.characters.get(position) {
+      Some(character) => *character,
+      None => '\0',
     }
   }
 
   /// Get the next character.
-  fn get_next_character(&mut self) -> &'static str {
-    if self.next_position >= self.file.content.len() {
-      ""
-    } else {
-      utils::character_to_str(self.file.content.as_bytes()[self.next_position])
-    }
+  fn get_next_character(&self) -> char {
+    self.get_character(self.next_position)
   }
 
   /// Get the two next character.
-  fn get_two_next_character(&mut self) -> &'static str {
-    if self.next_position + 1 >= self.file.content.len() {
-      ""
-    } else {
-      utils::character_to_str(self.file.content.as_bytes()[self.next_position + 1])
-    }
+  fn get_two_next_character(&self) -> char {
+    self.get_character(self.next_position + 1)
+  }
+
+  /// Get the characters from the start position until the current one.
+  fn get_characters_from(&self, start_position: usize) -> String {
+    self.characters[start_position..self.current_position].iter().collect()
   }
 
   /// Ignore the whitespaces in the current line.
   fn skip_whitespace(&mut self) {
     loop {
-      let current_character_str = utils::character_to_str(self.current_character.clone());
-
-      if current_character_str == "\n" {
+      if self.current_character == '\n' {
         self.current_line += 1;
         self.current_line_position = 0;
       }
 
-      if current_character_str != " " && current_character_str != "\t" && current_character_str != "\n" {
+      if self.current_character != ' ' && self.current_character != '\t' && self.current_character != '\n' {
         break;
       }
 
@@ -84,67 +113,396 @@ impl Lexer {
     }
   }
 
-  /// Ignore comments.
+  /// Ignore the line (`//`) and block (`/* */`) comments, and keep the doc comments (`///`)
+  /// for the next token.
   fn skip_comments(&mut self) {
-    if utils::character_to_str(self.current_character.clone()) == "/" &&
-      self.get_next_character() == "/" {
-      loop {
-        if utils::character_to_str(self.current_character.clone()) == "\n" {
-          self.skip_whitespace();
-          self.skip_comments();
-          break;
-        }
+    loop {
+      if self.current_character == '/' && self.get_next_character() == '/' {
+        self.read_line_comment();
+      } else if self.current_character == '/' && self.get_next_character() == '*' {
+        self.skip_block_comment();
+      } else {
+        break;
+      }
+
+      self.skip_whitespace();
+    }
+  }
+
+  /// Read a line comment, to the end of the line or the file.
+  fn read_line_comment(&mut self) {
+    let start_position = self.current_position;
+
+    while self.current_character != '\n' && self.current_character != '\0' {
+      self.read_next_character();
+    }
+
+    let comment = self.get_characters_from(start_position);
+
+    // Check if it is a doc comment, `////` is a line comment.
+    if comment.starts_with("///") && !comment.starts_with("////") {
+      let line = &comment[3..];
+
+      self.docs.push(line.strip_prefix(' ').unwrap_or(line).trim_end().to_string());
+    }
+  }
+
+  /// Skip a block comment, with the nested block comments inside it.
+  fn skip_block_comment(&mut self) {
+    let start_line = self.current_line;
+    let start_position = self.current_line_position;
+    let mut depth: usize = 0;
+
+    loop {
+      // Check if the file ends before the end of the comment.
+      if self.current_character == '\0' {
+        self.errors.push(Error::new(
+          String::from("the block comment is not closed."),
+          start_line,
+          start_position,
+          start_position + 2,
+        ));
+
+        return;
+      }
+
+      if self.current_character == '/' && self.get_next_character() == '*' {
+        depth += 1;
+
+        self.read_next_character();
+      } else if self.current_character == '*' && self.get_next_character() == '/' {
+        depth -= 1;
 
         self.read_next_character();
+        self.read_next_character();
+
+        if depth == 0 {
+          return;
+        }
+
+        continue;
+      } else if self.current_character == '\n' {
+        self.current_line += 1;
+        self.current_line_position = 0;
       }
+
+      self.read_next_character();
     }
   }
 
+  /// Get the doc comments (`///`) written before the token.
+  pub fn get_documentation(&self, token: &Token) -> Option<String> {
+    self.documentation.get(&(token.line, token.position)).cloned()
+  }
+
   fn read_identifier_or_keyword(&mut self) -> String {
     let start_position = self.current_position;
 
     loop {
       if !utils::is_letter_identifier(self.current_character) &&
-        !self.current_character.is_ascii_digit() {
-        return self.file.content[start_position..self.current_position].to_string();
+        !utils::is_number(self.current_character) {
+        return self.get_characters_from(start_position);
       }
 
       self.read_next_character();
     }
   }
 
+  /// Read the digits of the radix with the `_` separators between them, and get the error of
+  /// the separators.
+  fn read_digits(&mut self, radix: u32) -> Option<String> {
+    let mut digits: usize = 0;
+    let mut previous: char = '\0';
+    let mut help: Option<String> = None;
+
+    while self.current_character.is_digit(radix) || self.current_character == '_' {
+      if self.current_character == '_' {
+        // Check if the separator is not after a digit.
+        if digits == 0 || previous == '_' {
+          help = Some(String::from("the `_` separators must be between digits."));
+        }
+      } else {
+        digits += 1;
+      }
+
+      previous = self.current_character;
+      self.read_next_character();
+    }
+
+    // Check if there are no digits, a wrong digit, like the `8` of `0o8`, is reported later.
+    if digits == 0 &&
+      !utils::is_number(self.current_character) &&
+      !utils::is_letter_identifier(self.current_character) {
+      return Some(String::from("expect digits after the prefix, like `0xFF`."));
+    }
+
+    if previous == '_' {
+      return Some(String::from("the `_` separators must be between digits."));
+    }
+
+    help
+  }
+
+  /// Read a number, like `10`, `1.5`, `1e-9`, `1_000_000`, `0xFF`, `0b1010` or `0o17`, and
+  /// report it when it is malformed, like `1.2.3`.
   fn read_number(&mut self) -> String {
     let start_position = self.current_position;
-    let mut has_dot = false;
+    let start_line_position = self.current_line_position;
 
-    loop {
-      let character_as_str = utils::character_to_str(self.current_character);
+    let radix: u32 = match (self.current_character, self.get_next_character()) {
+      ('0', 'x') | ('0', 'X') => 16,
+      ('0', 'b') | ('0', 'B') => 2,
+      ('0', 'o') | ('0', 'O') => 8,
+      _ => 10,
+    };
+
+    let mut help: Option<String>;
+
+    if radix != 10 {
+      // Skip the prefix.
+      self.read_next_character();
+      self.read_next_character();
+
+      help = self.read_digits(radix);
+    } else {
+      help = self.read_digits(radix);
 
-      if !has_dot && character_as_str == "." {
-        has_dot = true;
+      // Check if the dot has decimals, the dot of a method call is not part of the number.
+      if self.current_character == '.' && utils::is_number(self.get_next_character()) {
         self.read_next_character();
+
+        help = help.or(self.read_digits(radix));
       }
 
-      if !utils::is_number(self.current_character) {
-        return self.file.content[start_position..self.current_position].to_string();
+      // Check if the number has an exponent.
+      if self.current_character == 'e' || self.current_character == 'E' {
+        self.read_next_character();
+
+        if self.current_character == '+' || self.current_character == '-' {
+          self.read_next_character();
+        }
+
+        if !utils::is_number(self.current_character) {
+          help = help.or(Some(String::from("expect the digits of the exponent, like `1e-9`.")));
+        }
+
+        help = help.or(self.read_digits(radix));
+      }
+    }
+
+    // Read the rest of a malformed number, like `1.2.3` or `0b102`.
+    while utils::is_letter_identifier(self.current_character) ||
+      utils::is_number(self.current_character) ||
+      (self.current_character == '.' && utils::is_number(self.get_next_character())) {
+      if help.is_none() {
+        help = Some(match self.current_character {
+          '.' => String::from("a number can only have one `.`."),
+          character => format!(
+            "`{}` is not {} digit.",
+            character,
+            match radix {
+              16 => "a hexadecimal",
+              8 => "an octal",
+              2 => "a binary",
+              _ => "a decimal",
+            },
+          ),
+        });
       }
 
       self.read_next_character();
     }
+
+    let value = self.get_characters_from(start_position);
+
+    if let Some(help) = help {
+      self.errors.push(Error::new(
+        format!("`{}` is not a valid number.", value),
+        self.current_line,
+        start_line_position,
+        start_line_position + value.chars().count(),
+      ).with_help(help));
+    }
+
+    value
   }
 
-  fn read_string(&mut self, quote: u8) -> String {
-    let start_position = self.current_position;
-    let mut readed_first_quote = false;
+  /// Read an escape sequence of a string, from the backslash, and get its character.
+  fn read_escape(&mut self) -> Option<char> {
+    let start_position = self.current_line_position;
+    let mut sequence = String::from("\\");
 
-    loop {
-      if self.current_character == quote {
-        if readed_first_quote {
+    // Skip the backslash.
+    self.read_next_character();
+
+    let character = self.current_character;
+
+    // The string is not closed.
+    if character == '\0' {
+      return None;
+    }
+
+    sequence.push(character);
+    self.read_next_character();
+
+    let escaped = match character {
+      'n' => Some('\n'),
+      't' => Some('\t'),
+      'r' => Some('\r'),
+      '0' => Some('\0'),
+      '\\' | '\'' | '"' | '`' | '$' => Some(character),
+      // Parse `\x41`.
+      'x' => {
+        let mut digits = String::new();
+
+        while digits.len() < 2 && self.current_character.is_ascii_hexdigit() {
+          digits.push(self.current_character);
+          self.read_next_character();
+        }
+
+        sequence.push_str(&digits);
+
+        if digits.len() == 2 {
+          u32::from_str_radix(&digits, 16).ok().and_then(char::from_u32)
+        } else {
+          None
+        }
+      },
+      // Parse `\u{1F600}`.
+      'u' if self.current_character == '{' => {
+        let mut digits = String::new();
+
+        sequence.push('{');
+        self.read_next_character();
+
+        while digits.len() < 6 && self.current_character.is_ascii_hexdigit() {
+          digits.push(self.current_character);
           self.read_next_character();
-          return self.file.content[start_position..self.current_position].to_string();
+        }
+
+        sequence.push_str(&digits);
+
+        if self.current_character == '}' && !digits.is_empty() {
+          sequence.push('}');
+          self.read_next_character();
+
+          u32::from_str_radix(&digits, 16).ok().and_then(char::from_u32)
         } else {
-          readed_first_quote = true;
+          None
         }
+      },
+      _ => None,
+    };
+
+    if escaped.is_none() {
+      self.errors.push(Error::new(
+        format!("`{}` is not a valid escape.", sequence),
+        self.current_line,
+        start_position,
+        start_position + sequence.chars().count(),
+      ));
+    }
+
+    escaped
+  }
+
+  /// Read a string and get it with the quotes and the escapes decoded.
+  fn read_string(&mut self, quote: char) -> String {
+    let start_position = self.current_line_position;
+    let mut value = quote.to_string();
+
+    // Skip the first quote.
+    self.read_next_character();
+
+    loop {
+      // Check if the file ends before the last quote.
+      if self.current_character == '\0' {
+        self.errors.push(Error::new(
+          String::from("the string is not closed."),
+          self.current_line,
+          start_position,
+          start_position + 1,
+        ));
+
+        value.push(quote);
+        return value;
+      }
+
+      if self.current_character == quote {
+        self.read_next_character();
+
+        value.push(quote);
+        return value;
+      }
+
+      if self.current_character == '\\' {
+        if let Some(character) = self.read_escape() {
+          value.push(character);
+        }
+
+        continue;
+      }
+
+      value.push(self.current_character);
+      self.read_next_character();
+    }
+  }
+
+  /// Read a part of a template string, from the backtick or the brace that closes an expression,
+  /// to the next backtick or `${`, and get it with the delimiters and the escapes decoded.
+  fn read_template(&mut self) -> String {
+    let start_line = self.current_line;
+    let start_position = self.current_line_position;
+    let mut value = self.current_character.to_string();
+
+    // Skip the backtick or the brace.
+    self.read_next_character();
+
+    loop {
+      // Check if the file ends before the last backtick.
+      if self.current_character == '\0' {
+        self.errors.push(Error::new(
+          String::from("the template string is not closed."),
+          start_line,
+          start_position,
+          start_position + 1,
+        ));
+
+        value.push('`');
+        return value;
+      }
+
+      if self.current_character == '`' {
+        self.read_next_character();
+
+        value.push('`');
+        return value;
+      }
+
+      // Check if an expression starts.
+      if self.current_character == '$' && self.get_next_character() == '{' {
+        self.read_next_character();
+        self.read_next_character();
+
+        self.templates.push(self.braces);
+
+        value.push_str("${");
+        return value;
+      }
+
+      if self.current_character == '\\' {
+        if let Some(character) = self.read_escape() {
+          value.push(character);
+        }
+
+        continue;
+      }
+
+      value.push(self.current_character);
+
+      if self.current_character == '\n' {
+        self.current_line += 1;
+        self.current_line_position = 0;
       }
 
       self.read_next_character();
@@ -156,20 +514,24 @@ impl Lexer {
     self.skip_whitespace();
     self.skip_comments();
 
-    if self.current_character == 0 {
+    if self.current_character == '\0' {
       // End Of File
       return Token::new(Box::new(Tokens::EOF), String::new(), self.current_line, self.current_line_position);
     }
 
     let start_position = self.current_line_position;
-    let current_character_str = utils::character_to_str(self.current_character);
-    let mut current_token = Token::from_value(current_character_str, self.current_line, start_position);
+    let mut current_token = Token::from_value(&self.current_character.to_string(), self.current_line, start_position);
 
     // Check if the current token is a string, keyword, identifier or number.
     if current_token.token.clone().is_illegal() {
-      if current_character_str == "\"" || current_character_str == "'" {
+      if self.current_character == '"' || self.current_character == '\'' {
         // Read strings.
         current_token = Token::new(Box::new(Tokens::STRING), self.read_string(self.current_character), self.current_line, start_position);
+      } else if self.current_character == '`' {
+        // Read template strings.
+        let line = self.current_line;
+
+        current_token = Token::new(Box::new(Tokens::TEMPLATE), self.read_template(), line, start_position);
       } else if utils::is_letter_identifier(self.current_character) {
         // Read identifier or keyword.
         current_token = Token::from_value(self.read_identifier_or_keyword().as_str(), self.current_line, start_position);
@@ -185,13 +547,27 @@ impl Lexer {
         // Read the next character.
         self.read_next_character();
       }
+    } else if current_token.token.expect_sign(&Signs::RIGHTBRACE) && self.templates.last() == Some(&self.braces) {
+      // Read the rest of the template string, after the expression.
+      let line = self.current_line;
+
+      self.templates.pop();
+
+      current_token = Token::new(Box::new(Tokens::TEMPLATE), self.read_template(), line, start_position);
     } else if let Some(sign) = current_token.token.clone().get_sign() {
+      // Count the braces, to find the end of the template expressions.
+      if sign == Signs::LEFTBRACE {
+        self.braces += 1;
+      } else if sign == Signs::RIGHTBRACE {
+        self.braces = self.braces.saturating_sub(1);
+      }
+
       // Get the next character.
       let next_character = self.get_next_character();
       let next_two_character = self.get_two_next_character();
 
-      // Parse "==", "===", "!=", "!==", "<=", ">=", "+=", "-=", "*=" and "/="
-      if next_character == "=" && (
+      // Parse "==", "===", "!=", "!==", "<=", ">=", "+=", "-=", "*=", "/=" and "%="
+      if next_character == '=' && (
         sign == Signs::ASSIGN ||
         sign == Signs::NOT ||
         sign == Signs::LESSTHAN ||
@@ -199,13 +575,14 @@ impl Lexer {
         sign == Signs::PLUS ||
         sign == Signs::MINUS ||
         sign == Signs::MULTIPLY ||
-        sign == Signs::DIVIDE
+        sign == Signs::DIVIDE ||
+        sign == Signs::MODULE
       ) {
         // Read the next character.
         self.read_next_character();
 
-        if next_two_character == "=" && sign != Signs::LESSTHAN && sign != Signs::GREATERTHAN &&
-          sign != Signs::PLUS && sign != Signs::MINUS && sign != Signs::MULTIPLY && sign != Signs::DIVIDE {
+        if next_two_character == '=' && sign != Signs::LESSTHAN && sign != Signs::GREATERTHAN &&
+          sign != Signs::PLUS && sign != Signs::MINUS && sign != Signs::MULTIPLY && sign != Signs::DIVIDE && sign != Signs::MODULE {
           // Read the next character.
           self.read_next_character();
 
@@ -225,12 +602,12 @@ impl Lexer {
         }
       }
       // Parse "++", "--", "**", "=>", "->", "&&" and "||"
-      else if (sign == Signs::PLUS && next_character == "+") ||
-        (sign == Signs::MULTIPLY && next_character == "*") ||
-        (sign == Signs::ASSIGN && next_character == ">") ||
-        (sign == Signs::MINUS && (next_character == "-" || next_character == ">")) ||
-        (sign == Signs::BITAND && next_character == "&") ||
-        (sign == Signs::BITOR && next_character == "|")
+      else if (sign == Signs::PLUS && next_character == '+') ||
+        (sign == Signs::MULTIPLY && next_character == '*') ||
+        (sign == Signs::ASSIGN && next_character == '>') ||
+        (sign == Signs::MINUS && (next_character == '-' || next_character == '>')) ||
+        (sign == Signs::BITAND && next_character == '&') ||
+        (sign == Signs::BITOR && next_character == '|')
       {
         // Read the next character.
         self.read_next_character();
@@ -243,7 +620,7 @@ impl Lexer {
         );
       }
       // Parse "..."
-      else if sign == Signs::DOT && next_character == "." && next_two_character == "." {
+      else if sign == Signs::DOT && next_character == '.' && next_two_character == '.' {
         // Read the next character.
         self.read_next_character();
 
@@ -258,6 +635,15 @@ impl Lexer {
       self.read_next_character();
     }
 
+    // Attach the doc comments to the token, the `export` keyword and the modifiers leave them for
+    // the next token.
+    if !self.docs.is_empty() &&
+      !current_token.token.expect_keyword(&Keywords::EXPORT) &&
+      !current_token.token.is_modifier() {
+      self.documentation.insert((current_token.line, current_token.position), self.docs.join("\n"));
+      self.docs.clear();
+    }
+
     current_token
   }
 }
@@ -1,22 +1,9 @@
-/// Convert a character to a str.
-pub fn character_to_str(character: u8) -> &'static str {
-  let mut characters = Vec::new();
-  characters.push(character);
-
-  match String::from_utf8(characters) {
-    Ok(string) => Box::leak(string.into_boxed_str()),
-    Err(_) => "",
-  }
-}
-
 /// Check if a character is a valid letter for an identifier.
-pub fn is_letter_identifier(character: u8) -> bool {
-  let character_as_str = character_to_str(character);
-
-  character.is_ascii_alphabetic() || character_as_str == "_" || character_as_str == "$"
+pub fn is_letter_identifier(character: char) -> bool {
+  character.is_alphabetic() || character == '_' || character == '$'
 }
 
 /// Check if a character is a valid number.
-pub fn is_number(character: u8) -> bool {
+pub fn is_number(character: char) -> bool {
   character.is_ascii_digit()
 }
@@ -1,3 +1,4 @@
+mod diagnostic;
 mod error;
 mod expressions;
 mod lexer;
@@ -6,6 +7,7 @@ mod precedence;
 mod statements;
 pub mod tokens;
 
+pub use diagnostic::*;
 pub use error::*;
 pub use expressions::*;
 pub use lexer::*;
@@ -14,17 +16,17 @@ pub use precedence::*;
 pub use statements::*;
 
 #[cfg(not(test))]
-pub fn run(file_name: String) -> Result<File, (Error, Option<File>)> {
+pub fn run(file_name: String) -> Result<File, (Vec<Error>, Option<File>)> {
   let path = std::path::Path::new(&file_name);
 
   // Check if the path is a file.
   if !path.exists() || !path.is_file() {
-    return Err((Error::new(format!("`{}` file does not exists.", file_name), 0, 0, 0), None));
+    return Err((vec![Error::new(format!("`{}` file does not exists.", file_name), 0, 0, 0)], None));
   }
 
   // Check if the file extension is `.sf`
   if path.extension().is_none() || path.extension().unwrap() != "sf" {
-    return Err((Error::new(format!("`{}` is not a Sflyn file.", file_name), 0, 0, 0), None));
+    return Err((vec![Error::new(format!("`{}` is not a Sflyn file.", file_name), 0, 0, 0)], None));
   }
 
   // Get the file content.
@@ -39,7 +41,12 @@ pub fn run(file_name: String) -> Result<File, (Error, Option<File>)> {
   // Create a new parser.
   let mut parser = Parser::new(lexer);
 
+  // Syntax errors of the file.
+  let mut errors: Vec<Error> = Vec::new();
+
   while !parser.current_token_is(Box::new(tokens::Tokens::EOF)) {
+    let depth = parser.get_depth();
+
     match parse_statement(&mut parser, false, false, false) {
       Ok(statement) => {
         // Add the statement to the file statements.
@@ -55,6 +62,14 @@ pub fn run(file_name: String) -> Result<File, (Error, Option<File>)> {
           else if let Some(function) = export.get_value().get_function() {
             file.exports.push(function.get_name().value);
           }
+          // Check if the export value is a class.
+          else if let Some(class) = export.get_value().get_class() {
+            file.exports.push(class.get_name().value);
+          }
+          // Check if the export value is an enum.
+          else if let Some(enum_s) = export.get_value().get_enum() {
+            file.exports.push(enum_s.get_name().value);
+          }
           // Check if the export value is an interface.
           else if let Some(interface) = export.get_value().get_interface() {
             file.exports.push(interface.get_name().value);
@@ -69,7 +84,14 @@ pub fn run(file_name: String) -> Result<File, (Error, Option<File>)> {
         }
       },
       Err(error) => {
-        return Err((error, Some(file)));
+        errors.push(error);
+
+        // Skip the rest of the statement and keep parsing.
+        parser.skip_statement(depth);
+
+        if parser.current_token_is(Box::new(tokens::Tokens::EOF)) {
+          break;
+        }
       },
     }
 
@@ -77,5 +99,20 @@ pub fn run(file_name: String) -> Result<File, (Error, Option<File>)> {
     parser.next_token();
   }
 
+  // Add the errors of the tokens, like an unknown escape or a malformed number, in the order of
+  // the file. The error of a token is kept over the error of the parser in the same position.
+  let mut token_errors: Vec<Error> = parser.lexer.errors.drain(..).collect();
+
+  token_errors.append(&mut errors);
+  token_errors.append(&mut parser.errors);
+  errors = token_errors;
+
+  errors.sort_by_key(|error| (error.line, error.start_position));
+  errors.dedup_by_key(|error| (error.line, error.start_position));
+
+  if !errors.is_empty() {
+    return Err((errors, Some(file)));
+  }
+
   Ok(file)
 }
@@ -1,7 +1,9 @@
 use super::{
+  Error,
   Lexer,
   Precedence,
   tokens::{
+    Signs,
     Token,
     Tokens,
   },
@@ -13,6 +15,13 @@ pub struct Parser {
 
   current_token: Token,
   next_token: Token,
+
+  generics: Vec<String>,
+
+  depth: i32,
+
+  // Errors of the statements skipped inside the blocks.
+  pub errors: Vec<Error>,
 }
 
 impl Parser {
@@ -22,6 +31,12 @@ impl Parser {
 
       current_token: Token::new_empty(),
       next_token: Token::new_empty(),
+
+      generics: Vec::new(),
+
+      depth: 0,
+
+      errors: Vec::new(),
     };
 
     parser.next_token();
@@ -38,9 +53,44 @@ impl Parser {
     self.next_token.clone()
   }
 
+  /// Get the generic names that can be used in the current data types.
+  pub fn get_generics(&self) -> Vec<String> {
+    self.generics.clone()
+  }
+
+  pub fn set_generics(&mut self, generics: Vec<String>) {
+    self.generics = generics;
+  }
+
+  /// Get the number of open braces until the current token.
+  pub fn get_depth(&self) -> i32 {
+    self.depth
+  }
+
   pub fn next_token(&mut self) {
     std::mem::swap(&mut self.current_token, &mut self.next_token);
     self.next_token = self.lexer.read_next_token();
+
+    if self.current_token.token.expect_sign(&Signs::LEFTBRACE) {
+      self.depth += 1;
+    } else if self.current_token.token.expect_sign(&Signs::RIGHTBRACE) {
+      self.depth -= 1;
+    }
+  }
+
+  /// Skip the tokens until the end of the statement started at the depth, a `;` or a `}`.
+  pub fn skip_statement(&mut self, depth: i32) {
+    while !self.current_token_is(Box::new(Tokens::EOF)) {
+      if self.current_token_is(Signs::new(Signs::SEMICOLON)) && self.depth <= depth {
+        break;
+      }
+
+      if self.current_token_is(Signs::new(Signs::RIGHTBRACE)) && self.depth <= depth {
+        break;
+      }
+
+      self.next_token();
+    }
   }
 
   pub fn current_precedence(&mut self) -> Precedence {
@@ -71,6 +121,30 @@ impl Parser {
     self.next_token.token == token
   }
 
+  /// Get the `;` that ends the statement, when it follows the current token. Another expression
+  /// in the same line, like the `2` of `const a = 1 2;`, is an error.
+  pub fn expect_semicolon(&mut self) -> Result<(), Error> {
+    if self.expect_token(Signs::new(Signs::SEMICOLON)) {
+      return Ok(());
+    }
+
+    let next_token: Token = self.get_next_token();
+
+    if next_token.line == self.current_token.line && (
+      next_token.token.is_identifier() ||
+      next_token.token.is_number() ||
+      next_token.token.is_string() ||
+      next_token.token.is_template()
+    ) {
+      return Err(Error::from_token(
+        format!("expect `;`, got `{}` instead.", next_token.value),
+        next_token,
+      ));
+    }
+
+    Ok(())
+  }
+
   pub fn expect_token(&mut self, token: Box<Tokens>) -> bool {
     if self.next_token_is(token) {
       self.next_token();
@@ -49,7 +49,8 @@ impl Precedence {
       Signs::PLUSASSIGN |
       Signs::MINUSASSIGN |
       Signs::MULTIPLYASSIGN |
-      Signs::DIVIDEASSIGN => Precedence::ASSIGN,
+      Signs::DIVIDEASSIGN |
+      Signs::MODULEASSIGN => Precedence::ASSIGN,
 
       // OR
       Signs::OR => Precedence::OR,
@@ -1,5 +1,8 @@
 mod block;
+mod class;
 mod continue_break;
+mod do_while;
+mod enum_s;
 mod export;
 mod expression;
 mod for_s;
@@ -9,10 +12,17 @@ mod import;
 mod interface;
 mod return_s;
 mod statement;
+mod switch;
+mod throw;
+mod try_s;
 mod variable;
+mod while_s;
 
 pub use block::*;
+pub use class::*;
 pub use continue_break::*;
+pub use do_while::*;
+pub use enum_s::*;
 pub use export::*;
 pub use expression::*;
 pub use for_s::*;
@@ -22,26 +32,88 @@ pub use import::*;
 pub use interface::*;
 pub use return_s::*;
 pub use statement::*;
+pub use switch::*;
+pub use throw::*;
+pub use try_s::*;
 pub use variable::*;
+pub use while_s::*;
 
 use super::{
   Error,
   Parser,
-  tokens::Keywords,
+  tokens::{
+    Keywords,
+    Signs,
+    Token,
+    Tokens,
+  },
 };
 
+/// Parse the member modifiers (`public`, `private`, `protected`, `internal` and `readonly`)
+/// starting at the current token, and leave the current token at the member.
+pub fn parse_modifiers(parser: &mut Parser) -> Result<Vec<Token>, Error> {
+  let mut modifiers: Vec<Token> = Vec::new();
+
+  while parser.get_current_token().token.is_modifier() {
+    let modifier: Token = parser.get_current_token();
+    let keyword: Keywords = modifier.token.get_keyword().unwrap();
+
+    for previous in modifiers.iter() {
+      let previous_keyword: Keywords = previous.token.get_keyword().unwrap();
+
+      // Check if the modifier is repeated.
+      if previous_keyword == keyword {
+        return Err(Error::from_token(
+          format!("`{}` modifier already seen.", modifier.value),
+          modifier,
+        ));
+      }
+
+      // Check if there are two access modifiers.
+      if previous_keyword.is_access_modifier() && keyword.is_access_modifier() {
+        return Err(Error::from_token(
+          format!("`{}` can not be used with `{}`.", modifier.value, previous.value),
+          modifier,
+        ));
+      }
+    }
+
+    modifiers.push(modifier);
+
+    // Get the next token.
+    parser.next_token();
+  }
+
+  Ok(modifiers)
+}
+
 pub fn parse_statement<'a>(
   parser: &'a mut Parser,
   standard_library: bool,
-  _from_class: bool,
+  from_class: bool,
   with_this: bool,
 ) -> Result<Box<Statements>, Error> {
+  // Class
+  if parser.current_token_is(Keywords::new(Keywords::CLASS)) {
+    return Class::parse(parser, standard_library);
+  }
+
   // Continue and Break
   if parser.current_token_is(Keywords::new(Keywords::CONTINUE)) ||
     parser.current_token_is(Keywords::new(Keywords::BREAK)) {
     return Ok(ContinueBreak::parse(parser));
   }
 
+  // Do while
+  if parser.current_token_is(Keywords::new(Keywords::DO)) {
+    return DoWhile::parse(parser, standard_library, with_this);
+  }
+
+  // Enum
+  if parser.current_token_is(Keywords::new(Keywords::ENUM)) {
+    return Enum::parse(parser, standard_library, with_this);
+  }
+
   // Export
   if parser.current_token_is(Keywords::new(Keywords::EXPORT)) {
     return Export::parse(parser, standard_library);
@@ -52,8 +124,12 @@ pub fn parse_statement<'a>(
     return For::parse(parser, standard_library, with_this);
   }
 
-  // Function
-  if parser.current_token_is(Keywords::new(Keywords::FUNCTION)) {
+  // Function and class method
+  if parser.current_token_is(Keywords::new(Keywords::FUNCTION)) || (
+    from_class &&
+    parser.current_token_is(Box::new(Tokens::IDENTIFIER)) &&
+    parser.next_token_is(Signs::new(Signs::LEFTPARENTHESES))
+  ) {
     return Function::parse(parser, standard_library, with_this);
   }
 
@@ -77,12 +153,32 @@ pub fn parse_statement<'a>(
     return Return::parse(parser, standard_library, with_this);
   }
 
+  // Switch
+  if parser.current_token_is(Keywords::new(Keywords::SWITCH)) {
+    return Switch::parse(parser, standard_library, with_this);
+  }
+
+  // Throw
+  if parser.current_token_is(Keywords::new(Keywords::THROW)) {
+    return Throw::parse(parser, standard_library, with_this);
+  }
+
+  // Try
+  if parser.current_token_is(Keywords::new(Keywords::TRY)) {
+    return Try::parse(parser, standard_library, with_this);
+  }
+
   // Variable
   if parser.current_token_is(Keywords::new(Keywords::LET)) ||
     parser.current_token_is(Keywords::new(Keywords::CONST)) {
     return Variable::parse(parser, standard_library, with_this);
   }
 
+  // While
+  if parser.current_token_is(Keywords::new(Keywords::WHILE)) {
+    return While::parse(parser, standard_library, with_this);
+  }
+
   // Default
   ExpressionStatement::parse(parser, standard_library, with_this)
 }
@@ -55,6 +55,10 @@ impl Block {
     Box::new(Statements::BLOCK(Statement::from_token(token)))
   }
 
+  pub fn new_box_full(token: Token, statements: Vec<Box<Statements>>) -> Box<Statements> {
+    Box::new(Statements::BLOCK(Block { token, statements }))
+  }
+
   pub fn get_statements(&self) -> Vec<Box<Statements>> {
     self.statements.clone()
   }
@@ -73,13 +77,23 @@ impl Block {
     // Parse block statements.
     while !parser.current_token_is(Signs::new(Signs::RIGHTBRACE)) &&
       !parser.current_token_is(Box::new(Tokens::EOF)) {
+      let depth = parser.get_depth();
+
       // Parse statement
       match parse_statement(parser, standard_library, from_class, with_this) {
         Ok(statement) => {
           block.statements.push(statement);
         },
         Err(error) => {
-          return Err(error);
+          parser.errors.push(error);
+
+          // Skip the rest of the statement and keep parsing the block.
+          parser.skip_statement(depth);
+
+          // Check if the statement ends the block.
+          if parser.get_depth() < depth || parser.current_token_is(Box::new(Tokens::EOF)) {
+            break;
+          }
         }
       }
 
@@ -87,6 +101,14 @@ impl Block {
       parser.next_token();
     }
 
+    // Check if the file ends before the block is closed.
+    if parser.current_token_is(Box::new(Tokens::EOF)) {
+      parser.errors.push(Error::from_token(
+        String::from("expect `}` to close the block."),
+        block.token.clone(),
+      ));
+    }
+
     // Return the block statement.
     Ok(Box::new(Statements::BLOCK(block)))
   }
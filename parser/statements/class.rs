@@ -0,0 +1,206 @@
+use crate::{
+  Argument,
+  Error,
+  Expressions,
+  Parser,
+  tokens::{
+    Keywords,
+    Signs,
+    Token,
+    Tokens,
+  },
+};
+
+use super::{
+  Function,
+  parse_modifiers,
+  parse_statement,
+  Statement,
+  Statements,
+  Variable,
+};
+
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Class {
+  token: Token,
+  name: Token,
+  arguments: Vec<Expressions>,
+  fields: Vec<Variable>,
+  methods: Vec<Function>,
+  modifiers: HashMap<String, Vec<Token>>,
+}
+
+impl Statement for Class {
+  fn new() -> Self {
+    Self {
+      token: Token::new_empty(),
+      name: Token::new_empty(),
+      arguments: Vec::new(),
+      fields: Vec::new(),
+      methods: Vec::new(),
+      modifiers: HashMap::new(),
+    }
+  }
+
+  fn from_token(token: Token) -> Self {
+    let mut class: Self = Statement::new();
+
+    class.token = token;
+
+    class
+  }
+
+  fn get_token(&self) -> Token {
+    self.token.clone()
+  }
+
+  fn string(&self) -> String {
+    let mut arguments: Vec<String> = Vec::new();
+
+    for argument in self.get_arguments().iter() {
+      arguments.push(argument.string());
+    }
+
+    let mut members: Vec<String> = Vec::new();
+
+    for field in self.get_fields().iter() {
+      members.push(self.member_string(&field.get_name().value, field.string()));
+    }
+
+    for method in self.get_methods().iter() {
+      members.push(self.member_string(&method.get_name().value, method.string()));
+    }
+
+    format!(
+      "{} {}({}) {{\n{}\n}}",
+      self.get_token().value,
+      self.get_name().value,
+      arguments.join(", "),
+      members.join("\n"),
+    )
+  }
+}
+
+impl Class {
+  pub fn get_name(&self) -> Token {
+    self.name.clone()
+  }
+
+  pub fn get_arguments(&self) -> Vec<Box<Expressions>> {
+    self.arguments.iter().cloned().map(Box::new).collect()
+  }
+
+  pub fn get_fields(&self) -> Vec<Variable> {
+    self.fields.clone()
+  }
+
+  pub fn get_methods(&self) -> Vec<Function> {
+    self.methods.clone()
+  }
+
+  /// Get the modifiers of a class member.
+  pub fn get_modifiers(&self, name: &String) -> Vec<Token> {
+    match self.modifiers.get(name) {
+      Some(modifiers) => modifiers.clone(),
+      None => Vec::new(),
+    }
+  }
+
+  fn member_string(&self, name: &String, member: String) -> String {
+    let mut modifiers: Vec<String> = Vec::new();
+
+    for modifier in self.get_modifiers(name).iter() {
+      modifiers.push(modifier.value.clone());
+    }
+
+    modifiers.push(member);
+
+    modifiers.join(" ")
+  }
+
+  pub fn parse(
+    parser: &mut Parser,
+    standard_library: bool,
+  ) -> Result<Box<Statements>, Error> {
+    let mut class: Class = Statement::from_token(parser.get_current_token());
+
+    // Check if the next token is a valid identifier.
+    if !parser.expect_token(Box::new(Tokens::IDENTIFIER)) {
+      return Err(Error::from_token(
+        format!("`{}` is not a valid class name.", parser.get_next_token().value),
+        parser.get_next_token(),
+      ));
+    }
+
+    // Set the class name.
+    class.name = parser.get_current_token();
+
+    // Check if the next token is a left parentheses.
+    if parser.expect_token(Signs::new(Signs::LEFTPARENTHESES)) {
+      // Parse constructor arguments.
+      match Argument::parse(parser, standard_library, true) {
+        Ok(arguments) => {
+          class.arguments = arguments.into_iter().map(|argument| *argument).collect();
+        },
+        Err(error) => {
+          return Err(error);
+        },
+      }
+    }
+
+    // Check if the next token is a left brace.
+    if !parser.expect_token(Signs::new(Signs::LEFTBRACE)) {
+      return Err(Error::from_token(
+        format!("expect `{{`, got `{}` instead.", parser.get_next_token().value),
+        parser.get_next_token(),
+      ));
+    }
+
+    // Get the next token.
+    parser.next_token();
+
+    // Parse class members.
+    while !parser.current_token_is(Signs::new(Signs::RIGHTBRACE)) &&
+      !parser.current_token_is(Box::new(Tokens::EOF)) {
+      // Parse member modifiers.
+      let modifiers: Vec<Token> = parse_modifiers(parser)?;
+
+      match parse_statement(parser, standard_library, true, true) {
+        Ok(statement) => {
+          if let Some(variable) = statement.get_variable() {
+            class.modifiers.insert(variable.get_name().value, modifiers);
+            class.fields.push(variable);
+          } else if let Some(function) = statement.get_function() {
+            // Check if the method has a `readonly` modifier.
+            for modifier in modifiers.iter() {
+              if modifier.token.expect_keyword(&Keywords::READONLY) {
+                return Err(Error::from_token(
+                  String::from("`readonly` only can be used with fields."),
+                  modifier.clone(),
+                ));
+              }
+            }
+
+            class.modifiers.insert(function.get_name().value, modifiers);
+            class.methods.push(function);
+          } else {
+            return Err(Error::from_token(
+              String::from("only can declare fields and methods in a class."),
+              statement.token(),
+            ));
+          }
+        },
+        Err(error) => {
+          return Err(error);
+        },
+      }
+
+      // Get the next token.
+      parser.next_token();
+    }
+
+    Ok(Box::new(Statements::CLASS(class)))
+  }
+}
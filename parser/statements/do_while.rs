@@ -0,0 +1,135 @@
+use crate::{
+  Block,
+  Error,
+  Expressions,
+  Infix,
+  parse_expression,
+  Parser,
+  Precedence,
+  Statement,
+  Statements,
+  tokens::{
+    Keywords,
+    Signs,
+    Token,
+  },
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DoWhile {
+  token: Token,
+  body: Box<Statements>,
+  condition: Box<Expressions>,
+}
+
+impl Statement for DoWhile {
+  fn new() -> Self {
+    Self {
+      token: Token::new_empty(),
+      body: Block::new_box(),
+      condition: Infix::new_box(),
+    }
+  }
+
+  fn from_token(token: Token) -> Self {
+    let mut do_while: Self = Statement::new();
+
+    do_while.token = token;
+
+    do_while
+  }
+
+  fn get_token(&self) -> Token {
+    self.token.clone()
+  }
+
+  fn string(&self) -> String {
+    format!(
+      "{} {} while ({});",
+      self.get_token().value,
+      self.get_body().string(),
+      self.get_condition().string(),
+    )
+  }
+}
+
+impl DoWhile {
+  pub fn get_body(&self) -> Box<Statements> {
+    self.body.clone()
+  }
+
+  pub fn get_condition(&self) -> Box<Expressions> {
+    self.condition.clone()
+  }
+
+  pub fn parse(
+    parser: &mut Parser,
+    standard_library: bool,
+    with_this: bool,
+  ) -> Result<Box<Statements>, Error> {
+    let mut do_while: Self = Statement::from_token(parser.get_current_token());
+
+    // Check if the next token is a left brace.
+    if !parser.expect_token(Signs::new(Signs::LEFTBRACE)) {
+      return Err(Error::from_token(
+        format!("expect `{{`, got `{}` instead.", parser.get_next_token().value),
+        parser.get_next_token(),
+      ));
+    }
+
+    // Parse body.
+    match Block::parse(parser, standard_library, false, with_this) {
+      Ok(body) => {
+        do_while.body = body;
+      },
+      Err(error) => {
+        return Err(error);
+      },
+    }
+
+    // Check if the next token is a `while`.
+    if !parser.expect_token(Keywords::new(Keywords::WHILE)) {
+      return Err(Error::from_token(
+        format!("expect `while`, got `{}` instead.", parser.get_next_token().value),
+        parser.get_next_token(),
+      ));
+    }
+
+    // Check if the next token is a left parentheses.
+    if !parser.expect_token(Signs::new(Signs::LEFTPARENTHESES)) {
+      return Err(Error::from_token(
+        format!("expect `(`, got `{}` instead.", parser.get_next_token().value),
+        parser.get_next_token(),
+      ));
+    }
+
+    // Get the next token.
+    parser.next_token();
+
+    // Parse condition expression.
+    match parse_expression(parser, Precedence::LOWEST, standard_library, with_this) {
+      Ok(condition) => {
+        do_while.condition = condition;
+      },
+      Err(error) => {
+        return Err(error);
+      },
+    }
+
+    // Check if the next token is a right parentheses.
+    if !parser.expect_token(Signs::new(Signs::RIGHTPARENTHESES)) {
+      return Err(Error::from_token(
+        format!("expect `)`, got `{}` instead.", parser.get_next_token().value),
+        parser.get_next_token(),
+      ));
+    }
+
+    // Check if the next token is a semicolon.
+    if parser.next_token_is(Signs::new(Signs::SEMICOLON)) {
+      // Get the next token.
+      parser.next_token();
+    }
+
+    Ok(Box::new(Statements::DOWHILE(do_while)))
+  }
+}
@@ -0,0 +1,171 @@
+use crate::{
+  Error,
+  Expressions,
+  parse_expression,
+  Parser,
+  Precedence,
+  tokens::{
+    Signs,
+    Token,
+    Tokens,
+  },
+};
+
+use super::{
+  Statement,
+  Statements,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnumVariant {
+  token: Token,
+  value: Option<Box<Expressions>>,
+}
+
+impl EnumVariant {
+  pub fn get_token(&self) -> Token {
+    self.token.clone()
+  }
+
+  pub fn get_value(&self) -> Option<Box<Expressions>> {
+    self.value.clone()
+  }
+
+  pub fn string(&self) -> String {
+    match self.get_value() {
+      Some(value) => format!("{} = {}", self.get_token().value, value.string()),
+      None => self.get_token().value,
+    }
+  }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Enum {
+  token: Token,
+  name: Token,
+  variants: Vec<EnumVariant>,
+}
+
+impl Statement for Enum {
+  fn new() -> Self {
+    Self {
+      token: Token::new_empty(),
+      name: Token::new_empty(),
+      variants: Vec::new(),
+    }
+  }
+
+  fn from_token(token: Token) -> Self {
+    let mut enum_s: Self = Statement::new();
+
+    enum_s.token = token;
+
+    enum_s
+  }
+
+  fn get_token(&self) -> Token {
+    self.token.clone()
+  }
+
+  fn string(&self) -> String {
+    let mut variants: Vec<String> = Vec::new();
+
+    for variant in self.get_variants().iter() {
+      variants.push(variant.string());
+    }
+
+    format!(
+      "{} {} {{\n {} \n}}",
+      self.get_token().value,
+      self.get_name().value,
+      variants.join(",\n"),
+    )
+  }
+}
+
+impl Enum {
+  pub fn get_name(&self) -> Token {
+    self.name.clone()
+  }
+
+  pub fn get_variants(&self) -> Vec<EnumVariant> {
+    self.variants.clone()
+  }
+
+  pub fn parse(
+    parser: &mut Parser,
+    standard_library: bool,
+    with_this: bool,
+  ) -> Result<Box<Statements>, Error> {
+    let mut enum_s: Self = Statement::from_token(parser.get_current_token());
+
+    // Check if the next token is an identifier.
+    if !parser.expect_token(Box::new(Tokens::IDENTIFIER)) {
+      return Err(Error::from_token(
+        format!("`{}` is not a valid enum name.", parser.get_next_token().value),
+        parser.get_next_token(),
+      ));
+    }
+
+    // Set the current token as the enum name.
+    enum_s.name = parser.get_current_token();
+
+    // Check if the next token is a left brace.
+    if !parser.expect_token(Signs::new(Signs::LEFTBRACE)) {
+      return Err(Error::from_token(
+        format!("expect `{{`, got `{}` instead.", parser.get_next_token().value),
+        parser.get_next_token(),
+      ));
+    }
+
+    while !parser.next_token_is(Signs::new(Signs::RIGHTBRACE)) &&
+      !parser.next_token_is(Box::new(Tokens::EOF)) {
+      // Check if the next token is an identifier.
+      if !parser.expect_token(Box::new(Tokens::IDENTIFIER)) {
+        return Err(Error::from_token(
+          format!("`{}` is not a valid enum variant.", parser.get_next_token().value),
+          parser.get_next_token(),
+        ));
+      }
+
+      let mut variant = EnumVariant {
+        token: parser.get_current_token(),
+        value: None,
+      };
+
+      // Check if the next token is an assign sign.
+      if parser.expect_token(Signs::new(Signs::ASSIGN)) {
+        // Get the next token.
+        parser.next_token();
+
+        // Parse the variant value.
+        match parse_expression(parser, Precedence::LOWEST, standard_library, with_this) {
+          Ok(value) => {
+            variant.value = Some(value);
+          },
+          Err(error) => {
+            return Err(error);
+          },
+        }
+      }
+
+      // Add the variant to the enum.
+      enum_s.variants.push(variant);
+
+      // Check if the next token is a comma.
+      if !parser.expect_token(Signs::new(Signs::COMMA)) {
+        break;
+      }
+    }
+
+    // Check if the next token is a right brace.
+    if !parser.expect_token(Signs::new(Signs::RIGHTBRACE)) {
+      return Err(Error::from_token(
+        format!("expect `}}`, got `{}` instead.", parser.get_next_token().value),
+        parser.get_next_token(),
+      ));
+    }
+
+    Ok(Box::new(Statements::ENUM(enum_s)))
+  }
+}
@@ -69,6 +69,9 @@ impl ExpressionStatement {
       },
     }
 
+    // Get the semicolon that ends the statement, like in `i++;`.
+    parser.expect_semicolon()?;
+
     // Return statement.
     Ok(Box::new(Statements::EXPRESSION(statement)))
   }
@@ -2,6 +2,7 @@ use crate::{
   Argument,
   Error,
   Expressions,
+  parse_generics,
   parse_type,
   Parser,
   tokens::*,
@@ -17,9 +18,11 @@ use super::{
 pub struct Function {
   token: Token,
   name: Token,
+  generics: Vec<Token>,
   arguments: Vec<Box<Expressions>>,
   data_type: Token,
   body: Box<Statements>,
+  documentation: Option<String>,
 }
 
 impl Statement for Function {
@@ -27,9 +30,11 @@ impl Statement for Function {
     Self {
       token: Token::new_empty(),
       name: Token::new_empty(),
+      generics: Vec::new(),
       arguments: Vec::new(),
       data_type: Token::from_value("void", 0, 0),
       body: Block::new_box(),
+      documentation: None,
     }
   }
 
@@ -46,15 +51,21 @@ impl Statement for Function {
   }
 
   fn string(&self) -> String {
+    let mut generics: Vec<String> = Vec::new();
     let mut arguments: Vec<String> = Vec::new();
 
+    for generic in self.get_generics().iter() {
+      generics.push(generic.value.clone());
+    }
+
     for argument in self.get_arguments().iter() {
       arguments.push(argument.string());
     }
 
     format!(
-      "function {}({}): {} {}",
+      "function {}{}({}): {} {}",
       self.get_name().value,
+      if generics.is_empty() { String::new() } else { format!("<{}>", generics.join(", ")) },
       arguments.join(", "),
       self.get_type().value,
       self.get_body().string(),
@@ -67,6 +78,10 @@ impl Function {
     self.name.clone()
   }
 
+  pub fn get_generics(&self) -> Vec<Token> {
+    self.generics.clone()
+  }
+
   pub fn get_type(&self) -> Token {
     self.data_type.clone()
   }
@@ -79,6 +94,11 @@ impl Function {
     self.body.clone()
   }
 
+  /// Get the doc comments (`///`) written before the function.
+  pub fn get_documentation(&self) -> Option<String> {
+    self.documentation.clone()
+  }
+
   pub fn parse<'a>(
     parser: &'a mut Parser,
     standard_library: bool,
@@ -86,8 +106,15 @@ impl Function {
   ) -> Result<Box<Statements>, Error> {
     let mut function: Function = Statement::from_token(parser.get_current_token());
 
+    function.documentation = parser.lexer.get_documentation(&function.token);
+
+    // Check if the current token is a class method name.
+    if parser.current_token_is(Box::new(Tokens::IDENTIFIER)) {
+      // Set the method name.
+      function.name = parser.get_current_token();
+    }
     // Check if the next token is a valid identifier.
-    if !parser.expect_token(Box::new(Tokens::IDENTIFIER)) {
+    else if !parser.expect_token(Box::new(Tokens::IDENTIFIER)) {
       let mut message = format!("`{}` is not a valid function name.", parser.get_next_token().value);
 
       if parser.next_token_is(Signs::new(Signs::LEFTPARENTHESES)) {
@@ -98,10 +125,33 @@ impl Function {
         message,
         parser.get_next_token(),
       ));
+    } else {
+      // Set the function name.
+      function.name = parser.get_current_token();
     }
 
-    // Set the function name.
-    function.name = parser.get_current_token();
+    // Check if the next token is a less than.
+    if parser.expect_token(Signs::new(Signs::LESSTHAN)) {
+      // Parse generics.
+      match parse_generics(parser) {
+        Ok(generics) => {
+          function.generics = generics;
+        },
+        Err(error) => {
+          return Err(error);
+        },
+      }
+    }
+
+    let outer_generics: Vec<String> = parser.get_generics();
+    let mut generics: Vec<String> = outer_generics.clone();
+
+    for generic in function.generics.iter() {
+      generics.push(generic.value.clone());
+    }
+
+    // Set the generics for the function data types.
+    parser.set_generics(generics);
 
     // Check if the next token is a left parentheses.
     if !parser.expect_token(Signs::new(Signs::LEFTPARENTHESES)) {
@@ -159,6 +209,9 @@ impl Function {
       },
     }
 
+    // Restore the outer generics.
+    parser.set_generics(outer_generics);
+
     // Return function statement.
     Ok(Box::new(Statements::FUNCTION(function.clone())))
   }
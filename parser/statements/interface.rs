@@ -1,8 +1,10 @@
 use crate::{
   Error,
+  parse_generics,
   parse_type,
   Parser,
   tokens::{
+    Keywords,
     Signs,
     Token,
     Tokens,
@@ -10,6 +12,7 @@ use crate::{
 };
 
 use super::{
+  parse_modifiers,
   Statement,
   Statements,
 };
@@ -18,6 +21,8 @@ use super::{
 pub struct InterfaceMethod {
   token: Token,
   data_type: Token,
+  modifiers: Vec<Token>,
+  optional: bool,
 }
 
 impl InterfaceMethod {
@@ -29,10 +34,27 @@ impl InterfaceMethod {
     self.data_type.clone()
   }
 
+  pub fn get_modifiers(&self) -> Vec<Token> {
+    self.modifiers.clone()
+  }
+
+  /// Check if the member is optional (`name?: type`).
+  pub fn is_optional(&self) -> bool {
+    self.optional
+  }
+
   pub fn string(&self) -> String {
+    let mut modifiers: Vec<String> = Vec::new();
+
+    for modifier in self.modifiers.iter() {
+      modifiers.push(format!("{} ", modifier.value));
+    }
+
     format!(
-      "{}: {}",
+      "{}{}{}: {}",
+      modifiers.join(""),
       self.get_token().value,
+      if self.is_optional() { "?" } else { "" },
       self.get_type().value,
     )
   }
@@ -42,7 +64,9 @@ impl InterfaceMethod {
 pub struct Interface {
   token: Token,
   name: Token,
+  generics: Vec<Token>,
   methods: Vec<InterfaceMethod>,
+  documentation: Option<String>,
 }
 
 impl Statement for Interface {
@@ -50,8 +74,9 @@ impl Statement for Interface {
     Self {
       token: Token::new_empty(),
       name: Token::new_empty(),
-      
+      generics: Vec::new(),
       methods: Vec::new(),
+      documentation: None,
     }
   }
 
@@ -68,16 +93,22 @@ impl Statement for Interface {
   }
 
   fn string(&self) -> String {
+    let mut generics: Vec<String> = Vec::new();
     let mut methods: Vec<String> = Vec::new();
 
+    for generic in self.get_generics().iter() {
+      generics.push(generic.value.clone());
+    }
+
     for method in self.get_methods().iter() {
       methods.push(method.string());
     }
 
     format!(
-      "{} {} {{\n {} \n}}",
+      "{} {}{} {{\n {} \n}}",
       self.get_token().value,
       self.get_name().value,
+      if generics.is_empty() { String::new() } else { format!("<{}>", generics.join(", ")) },
       methods.join(";\n"),
     )
   }
@@ -88,10 +119,27 @@ impl Interface {
     self.name.clone()
   }
 
+  pub fn get_generics(&self) -> Vec<Token> {
+    self.generics.clone()
+  }
+
   pub fn get_methods(&self) -> Vec<InterfaceMethod> {
     self.methods.clone()
   }
 
+  /// Get the names of the optional members (`name?: type`).
+  pub fn get_optionals(&self) -> Vec<String> {
+    self.methods.iter()
+      .filter(|method| method.is_optional())
+      .map(|method| method.get_token().value)
+      .collect()
+  }
+
+  /// Get the doc comments (`///`) written before the interface.
+  pub fn get_documentation(&self) -> Option<String> {
+    self.documentation.clone()
+  }
+
   pub fn parse<'a>(
     parser: &'a mut Parser,
     _standard_library: bool,
@@ -99,6 +147,8 @@ impl Interface {
   ) -> Result<Box<Statements>, Error> {
     let mut interface: Self = Statement::from_token(parser.get_current_token());
 
+    interface.documentation = parser.lexer.get_documentation(&interface.token);
+
     // Check if the next token is an identifier.
     if !parser.expect_token(Box::new(Tokens::IDENTIFIER)) {
       return Err(Error::from_token(
@@ -110,6 +160,29 @@ impl Interface {
     // Set the current token as the interface name.
     interface.name = parser.get_current_token();
 
+    // Check if the next token is a less than.
+    if parser.expect_token(Signs::new(Signs::LESSTHAN)) {
+      // Parse generics.
+      match parse_generics(parser) {
+        Ok(generics) => {
+          interface.generics = generics;
+        },
+        Err(error) => {
+          return Err(error);
+        },
+      }
+    }
+
+    let outer_generics: Vec<String> = parser.get_generics();
+    let mut generics: Vec<String> = outer_generics.clone();
+
+    for generic in interface.generics.iter() {
+      generics.push(generic.value.clone());
+    }
+
+    // Set the generics for the members data types.
+    parser.set_generics(generics);
+
     // Check if the next token is a left brace.
     if !parser.expect_token(Signs::new(Signs::LEFTBRACE)) {
       return Err(Error::from_token(
@@ -118,17 +191,38 @@ impl Interface {
       ));
     }
 
-    while !parser.current_token_is(Signs::new(Signs::RIGHTBRACE)) {
-      // Check if the next token is an identifier.
-      if !parser.expect_token(Box::new(Tokens::IDENTIFIER)) {
+    while !parser.next_token_is(Signs::new(Signs::RIGHTBRACE)) &&
+      !parser.next_token_is(Box::new(Tokens::EOF)) {
+      // Get the next token.
+      parser.next_token();
+
+      // Parse member modifiers.
+      let modifiers: Vec<Token> = parse_modifiers(parser)?;
+
+      // Check if the modifiers are valid for an interface.
+      for modifier in modifiers.iter() {
+        if !modifier.token.expect_keyword(&Keywords::PUBLIC) &&
+          !modifier.token.expect_keyword(&Keywords::READONLY) {
+          return Err(Error::from_token(
+            format!("`{}` can not be used in an interface.", modifier.value),
+            modifier.clone(),
+          ));
+        }
+      }
+
+      // Check if the current token is an identifier.
+      if !parser.current_token_is(Box::new(Tokens::IDENTIFIER)) {
         return Err(Error::from_token(
-          format!("`{}` is not a valid identifier.", parser.get_next_token().value),
-          parser.get_next_token(),
+          format!("`{}` is not a valid identifier.", parser.get_current_token().value),
+          parser.get_current_token(),
         ));
       }
 
       let key = parser.get_current_token();
 
+      // Check if the next token is a question sign.
+      let optional = parser.expect_token(Signs::new(Signs::QUESTION));
+
       // Check if the next token is a colon.
       if !parser.expect_token(Signs::new(Signs::COLON)) {
         return Err(Error::from_token(
@@ -147,6 +241,8 @@ impl Interface {
           interface.methods.push(InterfaceMethod {
             token: key,
             data_type,
+            modifiers,
+            optional,
           });
         },
         Err(_) => {
@@ -163,11 +259,19 @@ impl Interface {
         // Get the next token.
         parser.next_token();
       }
+    }
 
-      // Get the next token.
-      parser.next_token();
+    // Check if the next token is a right brace.
+    if !parser.expect_token(Signs::new(Signs::RIGHTBRACE)) {
+      return Err(Error::from_token(
+        format!("expect `}}`, got `{}` instead.", parser.get_next_token().value),
+        parser.get_next_token(),
+      ));
     }
 
+    // Restore the outer generics.
+    parser.set_generics(outer_generics);
+
     Ok(Box::new(Statements::INTERFACE(interface)))
   }
 }
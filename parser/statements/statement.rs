@@ -19,7 +19,10 @@ pub trait Statement {
 #[derive(Debug, Clone, PartialEq)]
 pub enum Statements {
   BLOCK(Block),
+  CLASS(Class),
   CONTINUEBREAK(ContinueBreak),
+  DOWHILE(DoWhile),
+  ENUM(Enum),
   EXPORT(Export),
   EXPRESSION(ExpressionStatement),
   FOR(For),
@@ -28,7 +31,11 @@ pub enum Statements {
   IMPORT(Import),
   INTERFACE(Interface),
   RETURN(Return),
+  SWITCH(Switch),
+  THROW(Throw),
+  TRY(Try),
   VARIABLE(Variable),
+  WHILE(While),
 }
 
 impl Statements {
@@ -39,6 +46,13 @@ impl Statements {
     }
   }
 
+  pub fn get_class(&self) -> Option<Class> {
+    match self {
+      Statements::CLASS(class) => Some(class.clone()),
+      _ => None,
+    }
+  }
+
   pub fn get_continue_break(&self) -> Option<ContinueBreak> {
     match self {
       Statements::CONTINUEBREAK(continue_break) => Some(continue_break.clone()),
@@ -46,6 +60,20 @@ impl Statements {
     }
   }
 
+  pub fn get_do_while(&self) -> Option<DoWhile> {
+    match self {
+      Statements::DOWHILE(do_while) => Some(do_while.clone()),
+      _ => None,
+    }
+  }
+
+  pub fn get_enum(&self) -> Option<Enum> {
+    match self {
+      Statements::ENUM(enum_s) => Some(enum_s.clone()),
+      _ => None,
+    }
+  }
+
   pub fn get_export(&self) -> Option<Export> {
     match self {
       Statements::EXPORT(export) => Some(export.clone()),
@@ -102,6 +130,27 @@ impl Statements {
     }
   }
 
+  pub fn get_switch(&self) -> Option<Switch> {
+    match self {
+      Statements::SWITCH(switch) => Some(switch.clone()),
+      _ => None,
+    }
+  }
+
+  pub fn get_throw(&self) -> Option<Throw> {
+    match self {
+      Statements::THROW(throw) => Some(throw.clone()),
+      _ => None,
+    }
+  }
+
+  pub fn get_try(&self) -> Option<Try> {
+    match self {
+      Statements::TRY(try_s) => Some(try_s.clone()),
+      _ => None,
+    }
+  }
+
   pub fn get_variable(&self) -> Option<Variable> {
     match self {
       Statements::VARIABLE(variable) => Some(variable.clone()),
@@ -109,10 +158,20 @@ impl Statements {
     }
   }
 
+  pub fn get_while(&self) -> Option<While> {
+    match self {
+      Statements::WHILE(while_s) => Some(while_s.clone()),
+      _ => None,
+    }
+  }
+
   pub fn token(&self) -> Token {
     match self {
       Statements::BLOCK(block) => block.get_token(),
+      Statements::CLASS(class) => class.get_token(),
       Statements::CONTINUEBREAK(continue_break) => continue_break.get_token(),
+      Statements::DOWHILE(do_while) => do_while.get_token(),
+      Statements::ENUM(enum_s) => enum_s.get_token(),
       Statements::EXPORT(export) => export.get_token(),
       Statements::EXPRESSION(expression) => expression.get_token(),
       Statements::FOR(for_s) => for_s.get_token(),
@@ -121,14 +180,21 @@ impl Statements {
       Statements::IMPORT(import) => import.get_token(),
       Statements::INTERFACE(interface) => interface.get_token(),
       Statements::RETURN(return_s) => return_s.get_token(),
+      Statements::SWITCH(switch) => switch.get_token(),
+      Statements::THROW(throw) => throw.get_token(),
+      Statements::TRY(try_s) => try_s.get_token(),
       Statements::VARIABLE(variable) => variable.get_token(),
+      Statements::WHILE(while_s) => while_s.get_token(),
     }
   }
 
   pub fn string(&self) -> String {
     match self {
       Statements::BLOCK(block) => block.string(),
+      Statements::CLASS(class) => class.string(),
       Statements::CONTINUEBREAK(continue_break) => continue_break.string(),
+      Statements::DOWHILE(do_while) => do_while.string(),
+      Statements::ENUM(enum_s) => enum_s.string(),
       Statements::EXPORT(export) => export.string(),
       Statements::EXPRESSION(exp) => exp.string(),
       Statements::FOR(for_s) => for_s.string(),
@@ -137,7 +203,11 @@ impl Statements {
       Statements::IMPORT(import) => import.string(),
       Statements::INTERFACE(interface) => interface.string(),
       Statements::RETURN(return_s) => return_s.string(),
+      Statements::SWITCH(switch) => switch.string(),
+      Statements::THROW(throw) => throw.string(),
+      Statements::TRY(try_s) => try_s.string(),
       Statements::VARIABLE(variable) => variable.string(),
+      Statements::WHILE(while_s) => while_s.string(),
     }
   }
 }
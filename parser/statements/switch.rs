@@ -0,0 +1,312 @@
+use crate::{
+  Error,
+  Expressions,
+  Identifier,
+  parse_expression,
+  Parser,
+  Precedence,
+  tokens::{
+    Keywords,
+    Signs,
+    Token,
+    Tokens,
+  },
+};
+
+use super::{
+  Block,
+  parse_statement,
+  Statement,
+  Statements,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SwitchCase {
+  token: Token,
+  values: Vec<Expressions>,
+  consequence: Box<Statements>,
+}
+
+impl SwitchCase {
+  fn new() -> Self {
+    Self {
+      token: Token::new_empty(),
+      values: Vec::new(),
+      consequence: Block::new_box(),
+    }
+  }
+
+  pub fn get_token(&self) -> Token {
+    self.token.clone()
+  }
+
+  pub fn get_values(&self) -> Vec<Box<Expressions>> {
+    self.values.iter().cloned().map(Box::new).collect()
+  }
+
+  pub fn get_consequence(&self) -> Box<Statements> {
+    self.consequence.clone()
+  }
+
+  pub fn string(&self) -> String {
+    let mut values: Vec<String> = Vec::new();
+
+    for value in self.values.iter() {
+      values.push(value.string());
+    }
+
+    let mut statements: Vec<String> = Vec::new();
+
+    if let Some(block) = self.consequence.get_block() {
+      for statement in block.get_statements().iter() {
+        statements.push(statement.string());
+      }
+    }
+
+    if values.is_empty() {
+      return format!("{}:\n{}", self.token.value, statements.join("\n"));
+    }
+
+    format!(
+      "{} {}:\n{}",
+      self.token.value,
+      values.join(", "),
+      statements.join("\n"),
+    )
+  }
+
+  pub fn parse(
+    parser: &mut Parser,
+    standard_library: bool,
+    with_this: bool,
+  ) -> Result<SwitchCase, Error> {
+    let mut switch_case: Self = Self::new();
+
+    switch_case.token = parser.get_current_token();
+
+    // Check if the current token is a `case`.
+    if parser.current_token_is(Keywords::new(Keywords::CASE)) {
+      while !parser.next_token_is(Signs::new(Signs::COLON)) {
+        // Get the next token.
+        parser.next_token();
+
+        // Parse the case value.
+        match parse_expression(parser, Precedence::LOWEST, standard_library, with_this) {
+          Ok(value) => {
+            switch_case.values.push(*value);
+          },
+          Err(error) => {
+            return Err(error);
+          },
+        }
+
+        // Check if the next token is a comma.
+        if !parser.expect_token(Signs::new(Signs::COMMA)) {
+          break;
+        }
+      }
+    }
+
+    // Check if the next token is a colon.
+    if !parser.expect_token(Signs::new(Signs::COLON)) {
+      return Err(Error::from_token(
+        format!("expect `:`, got `{}` instead.", parser.get_next_token().value),
+        parser.get_next_token(),
+      ));
+    }
+
+    // Get the next token.
+    parser.next_token();
+
+    let mut statements: Vec<Box<Statements>> = Vec::new();
+
+    // Parse the case statements until the next arm.
+    while !parser.current_token_is(Keywords::new(Keywords::CASE)) &&
+      !parser.current_token_is(Keywords::new(Keywords::DEFAULT)) &&
+      !parser.current_token_is(Signs::new(Signs::RIGHTBRACE)) &&
+      !parser.current_token_is(Box::new(Tokens::EOF)) {
+      match parse_statement(parser, standard_library, false, with_this) {
+        Ok(statement) => {
+          statements.push(statement);
+        },
+        Err(error) => {
+          return Err(error);
+        },
+      }
+
+      // Get the next token.
+      parser.next_token();
+    }
+
+    switch_case.consequence = Block::new_box_full(switch_case.token.clone(), statements);
+
+    Ok(switch_case)
+  }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Switch {
+  token: Token,
+  value: Box<Expressions>,
+  cases: Vec<SwitchCase>,
+  default: Option<Box<Statements>>,
+}
+
+impl Statement for Switch {
+  fn new() -> Self {
+    Self {
+      token: Token::new_empty(),
+      value: Identifier::new_box(),
+      cases: Vec::new(),
+      default: None,
+    }
+  }
+
+  fn from_token(token: Token) -> Self {
+    let mut switch: Self = Statement::new();
+
+    switch.token = token;
+
+    switch
+  }
+
+  fn get_token(&self) -> Token {
+    self.token.clone()
+  }
+
+  fn string(&self) -> String {
+    let mut cases: Vec<String> = Vec::new();
+
+    for case in self.cases.iter() {
+      cases.push(case.string());
+    }
+
+    if let Some(default) = self.default.clone() {
+      let mut statements: Vec<String> = Vec::new();
+
+      if let Some(block) = default.get_block() {
+        for statement in block.get_statements().iter() {
+          statements.push(statement.string());
+        }
+      }
+
+      cases.push(format!("default:\n{}", statements.join("\n")));
+    }
+
+    format!(
+      "{} ({}) {{\n{}\n}}",
+      self.get_token().value,
+      self.get_value().string(),
+      cases.join("\n"),
+    )
+  }
+}
+
+impl Switch {
+  pub fn get_value(&self) -> Box<Expressions> {
+    self.value.clone()
+  }
+
+  pub fn get_cases(&self) -> Vec<SwitchCase> {
+    self.cases.clone()
+  }
+
+  pub fn get_default(&self) -> Option<Box<Statements>> {
+    self.default.clone()
+  }
+
+  pub fn parse(
+    parser: &mut Parser,
+    standard_library: bool,
+    with_this: bool,
+  ) -> Result<Box<Statements>, Error> {
+    let mut switch: Self = Statement::from_token(parser.get_current_token());
+
+    // Check if the next token is a left parentheses.
+    if !parser.expect_token(Signs::new(Signs::LEFTPARENTHESES)) {
+      return Err(Error::from_token(
+        format!("expect `(`, got `{}` instead.", parser.get_next_token().value),
+        parser.get_next_token(),
+      ));
+    }
+
+    // Get the next token.
+    parser.next_token();
+
+    // Parse the switch value.
+    match parse_expression(parser, Precedence::LOWEST, standard_library, with_this) {
+      Ok(value) => {
+        switch.value = value;
+      },
+      Err(error) => {
+        return Err(error);
+      },
+    }
+
+    // Check if the next token is a right parentheses.
+    if !parser.expect_token(Signs::new(Signs::RIGHTPARENTHESES)) {
+      return Err(Error::from_token(
+        format!("expect `)`, got `{}` instead.", parser.get_next_token().value),
+        parser.get_next_token(),
+      ));
+    }
+
+    // Check if the next token is a left brace.
+    if !parser.expect_token(Signs::new(Signs::LEFTBRACE)) {
+      return Err(Error::from_token(
+        format!("expect `{{`, got `{}` instead.", parser.get_next_token().value),
+        parser.get_next_token(),
+      ));
+    }
+
+    // Get the next token.
+    parser.next_token();
+
+    while !parser.current_token_is(Signs::new(Signs::RIGHTBRACE)) {
+      // Check if the current token is a `case`.
+      if parser.current_token_is(Keywords::new(Keywords::CASE)) {
+        match SwitchCase::parse(parser, standard_library, with_this) {
+          Ok(case) => {
+            // Add the case to the switch.
+            switch.cases.push(case);
+          },
+          Err(error) => {
+            return Err(error);
+          },
+        }
+
+        continue;
+      }
+
+      // Check if the current token is a `default`.
+      if parser.current_token_is(Keywords::new(Keywords::DEFAULT)) {
+        // Check if the switch already has a default arm.
+        if switch.default.is_some() {
+          return Err(Error::from_token(
+            String::from("the switch already has a `default` case."),
+            parser.get_current_token(),
+          ));
+        }
+
+        match SwitchCase::parse(parser, standard_library, with_this) {
+          Ok(case) => {
+            // Set the default case consequence.
+            switch.default = Some(case.get_consequence());
+          },
+          Err(error) => {
+            return Err(error);
+          },
+        }
+
+        continue;
+      }
+
+      return Err(Error::from_token(
+        format!("expect `case` or `default`, got `{}` instead.", parser.get_current_token().value),
+        parser.get_current_token(),
+      ));
+    }
+
+    Ok(Box::new(Statements::SWITCH(switch)))
+  }
+}
@@ -0,0 +1,87 @@
+use crate::{
+  Error,
+  Expressions,
+  Identifier,
+  parse_expression,
+  Parser,
+  Precedence,
+  tokens::{
+    Signs,
+    Token,
+  },
+};
+
+use super::{
+  Statement,
+  Statements,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Throw {
+  token: Token,
+  value: Box<Expressions>,
+}
+
+impl Statement for Throw {
+  fn new() -> Self {
+    Self {
+      token: Token::new_empty(),
+      value: Identifier::new_box(),
+    }
+  }
+
+  fn from_token(token: Token) -> Self {
+    let mut throw: Self = Statement::new();
+
+    throw.token = token;
+
+    throw
+  }
+
+  fn get_token(&self) -> Token {
+    self.token.clone()
+  }
+
+  fn string(&self) -> String {
+    format!(
+      "{} {};",
+      self.get_token().value,
+      self.get_value().string(),
+    )
+  }
+}
+
+impl Throw {
+  pub fn get_value(&self) -> Box<Expressions> {
+    self.value.clone()
+  }
+
+  pub fn parse(
+    parser: &mut Parser,
+    standard_library: bool,
+    with_this: bool,
+  ) -> Result<Box<Statements>, Error> {
+    let mut throw: Throw = Statement::from_token(parser.get_current_token());
+
+    // Get the next token.
+    parser.next_token();
+
+    // Parse the value.
+    match parse_expression(parser, Precedence::LOWEST, standard_library, with_this) {
+      Ok(value) => {
+        throw.value = value;
+      },
+      Err(error) => {
+        return Err(error);
+      },
+    }
+
+    // Check if the next token is a semicolon.
+    if parser.next_token_is(Signs::new(Signs::SEMICOLON)) {
+      // Get the next token.
+      parser.next_token();
+    }
+
+    Ok(Box::new(Statements::THROW(throw)))
+  }
+}
@@ -0,0 +1,183 @@
+use crate::{
+  Block,
+  Error,
+  Parser,
+  Statement,
+  Statements,
+  tokens::{
+    Keywords,
+    Signs,
+    Token,
+    Tokens,
+  },
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Try {
+  token: Token,
+  body: Box<Statements>,
+  catch_name: Option<Token>,
+  catch: Option<Box<Statements>>,
+  finally: Option<Box<Statements>>,
+}
+
+impl Statement for Try {
+  fn new() -> Self {
+    Self {
+      token: Token::new_empty(),
+      body: Block::new_box(),
+      catch_name: None,
+      catch: None,
+      finally: None,
+    }
+  }
+
+  fn from_token(token: Token) -> Self {
+    let mut try_s: Self = Statement::new();
+
+    try_s.token = token;
+
+    try_s
+  }
+
+  fn get_token(&self) -> Token {
+    self.token.clone()
+  }
+
+  fn string(&self) -> String {
+    let mut string = format!("{} {}", self.get_token().value, self.get_body().string());
+
+    if let (Some(name), Some(catch)) = (self.get_catch_name(), self.get_catch()) {
+      string = format!("{} catch ({}) {}", string, name.value, catch.string());
+    }
+
+    if let Some(finally) = self.get_finally() {
+      string = format!("{} finally {}", string, finally.string());
+    }
+
+    string
+  }
+}
+
+impl Try {
+  pub fn get_body(&self) -> Box<Statements> {
+    self.body.clone()
+  }
+
+  /// Get the name of the error in the catch block.
+  pub fn get_catch_name(&self) -> Option<Token> {
+    self.catch_name.clone()
+  }
+
+  pub fn get_catch(&self) -> Option<Box<Statements>> {
+    self.catch.clone()
+  }
+
+  pub fn get_finally(&self) -> Option<Box<Statements>> {
+    self.finally.clone()
+  }
+
+  pub fn parse(
+    parser: &mut Parser,
+    standard_library: bool,
+    with_this: bool,
+  ) -> Result<Box<Statements>, Error> {
+    let mut try_s: Try = Statement::from_token(parser.get_current_token());
+
+    // Check if the next token is a left brace.
+    if !parser.expect_token(Signs::new(Signs::LEFTBRACE)) {
+      return Err(Error::from_token(
+        format!("expect `{{`, got `{}` instead.", parser.get_next_token().value),
+        parser.get_next_token(),
+      ));
+    }
+
+    // Parse body.
+    match Block::parse(parser, standard_library, false, with_this) {
+      Ok(body) => {
+        try_s.body = body;
+      },
+      Err(error) => {
+        return Err(error);
+      },
+    }
+
+    // Check if the next token is a `catch`.
+    if parser.expect_token(Keywords::new(Keywords::CATCH)) {
+      // Check if the next token is a left parentheses.
+      if !parser.expect_token(Signs::new(Signs::LEFTPARENTHESES)) {
+        return Err(Error::from_token(
+          format!("expect `(`, got `{}` instead.", parser.get_next_token().value),
+          parser.get_next_token(),
+        ));
+      }
+
+      // Check if the next token is an identifier.
+      if !parser.expect_token(Box::new(Tokens::IDENTIFIER)) {
+        return Err(Error::from_token(
+          format!("`{}` is not a valid identifier.", parser.get_next_token().value),
+          parser.get_next_token(),
+        ));
+      }
+
+      try_s.catch_name = Some(parser.get_current_token());
+
+      // Check if the next token is a right parentheses.
+      if !parser.expect_token(Signs::new(Signs::RIGHTPARENTHESES)) {
+        return Err(Error::from_token(
+          format!("expect `)`, got `{}` instead.", parser.get_next_token().value),
+          parser.get_next_token(),
+        ));
+      }
+
+      // Check if the next token is a left brace.
+      if !parser.expect_token(Signs::new(Signs::LEFTBRACE)) {
+        return Err(Error::from_token(
+          format!("expect `{{`, got `{}` instead.", parser.get_next_token().value),
+          parser.get_next_token(),
+        ));
+      }
+
+      // Parse catch body.
+      match Block::parse(parser, standard_library, false, with_this) {
+        Ok(catch) => {
+          try_s.catch = Some(catch);
+        },
+        Err(error) => {
+          return Err(error);
+        },
+      }
+    }
+
+    // Check if the next token is a `finally`.
+    if parser.expect_token(Keywords::new(Keywords::FINALLY)) {
+      // Check if the next token is a left brace.
+      if !parser.expect_token(Signs::new(Signs::LEFTBRACE)) {
+        return Err(Error::from_token(
+          format!("expect `{{`, got `{}` instead.", parser.get_next_token().value),
+          parser.get_next_token(),
+        ));
+      }
+
+      // Parse finally body.
+      match Block::parse(parser, standard_library, false, with_this) {
+        Ok(finally) => {
+          try_s.finally = Some(finally);
+        },
+        Err(error) => {
+          return Err(error);
+        },
+      }
+    }
+
+    // Check if the try has a catch or a finally.
+    if try_s.catch.is_none() && try_s.finally.is_none() {
+      return Err(Error::from_token(
+        format!("expect `catch` or `finally`, got `{}` instead.", parser.get_next_token().value),
+        parser.get_next_token(),
+      ));
+    }
+
+    Ok(Box::new(Statements::TRY(try_s)))
+  }
+}
@@ -19,6 +19,7 @@ pub struct Variable {
   name: Token,
   data_type: Token,
   value: Option<Box<Expressions>>,
+  documentation: Option<String>,
 }
 
 impl Statement for Variable {
@@ -28,6 +29,7 @@ impl Statement for Variable {
       name: Token::new_empty(),
       data_type: Token::from_value("any", 0, 0),
       value: None,
+      documentation: None,
     }
   }
 
@@ -83,6 +85,11 @@ impl Variable {
     self.value.clone()
   }
 
+  /// Get the doc comments (`///`) written before the variable.
+  pub fn get_documentation(&self) -> Option<String> {
+    self.documentation.clone()
+  }
+
   pub fn parse<'a>(
     parser: &'a mut Parser,
     standard_library: bool,
@@ -90,6 +97,8 @@ impl Variable {
   ) -> Result<Box<Statements>, Error> {
     let mut variable: Variable = Statement::from_token(parser.get_current_token());
 
+    variable.documentation = parser.lexer.get_documentation(&variable.token);
+
     // Check if the next token is a valid identifier.
     if !parser.expect_token(Box::new(Tokens::IDENTIFIER)) {
       let mut message = format!("`{}` is not a valid variable name.", parser.get_next_token().value);
@@ -172,11 +181,8 @@ impl Variable {
       }
     }
 
-    // Check if the next token is a semicolon.
-    if parser.next_token_is(Signs::new(Signs::SEMICOLON)) {
-      // Get the next token.
-      parser.next_token();
-    }
+    // Get the semicolon that ends the statement.
+    parser.expect_semicolon()?;
 
     // Return the statement.
     Ok(Box::new(Statements::VARIABLE(variable.clone())))
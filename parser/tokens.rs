@@ -13,6 +13,7 @@ pub enum Tokens {
   ILLEGAL,
 
   STRING,
+  TEMPLATE,
   IDENTIFIER,
   NUMBER,
 
@@ -26,34 +27,27 @@ pub enum Tokens {
 impl Tokens {
   /// Check if the token is an illegal token.
   pub fn is_illegal(&self) -> bool {
-    match self {
-      Tokens::ILLEGAL => true,
-      _ => false,
-    }
+    matches!(self, Tokens::ILLEGAL)
   }
 
   /// Check if the token is a string token.
   pub fn is_string(&self) -> bool {
-    match self {
-      Tokens::STRING => true,
-      _ => false,
-    }
+    matches!(self, Tokens::STRING)
+  }
+
+  /// Check if the token is a part of a template string.
+  pub fn is_template(&self) -> bool {
+    matches!(self, Tokens::TEMPLATE)
   }
 
   /// Check if the token is an identifier token.
   pub fn is_identifier(&self) -> bool {
-    match self {
-      Tokens::IDENTIFIER => true,
-      _ => false,
-    }
+    matches!(self, Tokens::IDENTIFIER)
   }
 
   /// Check if the token is a number token.
   pub fn is_number(&self) -> bool {
-    match self {
-      Tokens::NUMBER => true,
-      _ => false,
-    }
+    matches!(self, Tokens::NUMBER)
   }
 
   /// Get the keyword token.
@@ -72,6 +66,14 @@ impl Tokens {
     }
   }
 
+  /// Check if the token is a member modifier keyword.
+  pub fn is_modifier(&self) -> bool {
+    match self {
+      Tokens::KEYWORD(keyword) => keyword.is_modifier(),
+      _ => false,
+    }
+  }
+
   /// Get the sign token.
   pub fn get_sign(&self) -> Option<Signs> {
     match self {
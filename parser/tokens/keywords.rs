@@ -28,6 +28,7 @@ pub enum Keywords {
   IF,
   ELSE,
   SWITCH,
+  CASE,
   IS,
 
   // Classes
@@ -44,6 +45,12 @@ pub enum Keywords {
   FROM,
   EXPORT,
 
+  // Exceptions
+  THROW,
+  TRY,
+  CATCH,
+  FINALLY,
+
   // Others
   DEFAULT,
   INTERFACE,
@@ -55,6 +62,22 @@ impl Keywords {
     Box::new(Tokens::KEYWORD(keyword))
   }
 
+  /// Check if the keyword is an access modifier.
+  pub fn is_access_modifier(&self) -> bool {
+    matches!(
+      self,
+      Keywords::PUBLIC |
+      Keywords::PRIVATE |
+      Keywords::PROTECTED |
+      Keywords::INTERNAL
+    )
+  }
+
+  /// Check if the keyword is a member modifier.
+  pub fn is_modifier(&self) -> bool {
+    self.is_access_modifier() || self == &Keywords::READONLY
+  }
+
   pub fn from_value(value: &str) -> Result<Keywords, ()> {
     match value {
       // Variables
@@ -83,6 +106,7 @@ impl Keywords {
       "if" => Ok(Keywords::IF),
       "else" => Ok(Keywords::ELSE),
       "switch" => Ok(Keywords::SWITCH),
+      "case" => Ok(Keywords::CASE),
       "is" => Ok(Keywords::IS),
 
       // Classes
@@ -99,6 +123,12 @@ impl Keywords {
       "from" => Ok(Keywords::FROM),
       "export" => Ok(Keywords::EXPORT),
 
+      // Exceptions
+      "throw" => Ok(Keywords::THROW),
+      "try" => Ok(Keywords::TRY),
+      "catch" => Ok(Keywords::CATCH),
+      "finally" => Ok(Keywords::FINALLY),
+
       // Others
       "default" => Ok(Keywords::DEFAULT),
       "interface" => Ok(Keywords::INTERFACE),
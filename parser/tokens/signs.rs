@@ -25,6 +25,7 @@ pub enum Signs {
   MINUSASSIGN,
   MULTIPLYASSIGN,
   DIVIDEASSIGN,
+  MODULEASSIGN,
 
   PLUSPLUS,
   MINUSMINUS,
@@ -56,6 +57,7 @@ pub enum Signs {
   AT,
   BITOR,
   BITAND,
+  QUESTION,
 }
 
 impl Signs {
@@ -88,6 +90,7 @@ impl Signs {
       "-=" => Ok(Signs::MINUSASSIGN),
       "*=" => Ok(Signs::MULTIPLYASSIGN),
       "/=" => Ok(Signs::DIVIDEASSIGN),
+      "%=" => Ok(Signs::MODULEASSIGN),
 
       "++" => Ok(Signs::PLUSPLUS),
       "--" => Ok(Signs::MINUSMINUS),
@@ -119,6 +122,7 @@ impl Signs {
       "@" => Ok(Signs::AT),
       "|" => Ok(Signs::BITOR),
       "&" => Ok(Signs::BITAND),
+      "?" => Ok(Signs::QUESTION),
 
       // Default
       _ => Err(()),
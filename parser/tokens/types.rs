@@ -1,10 +1,43 @@
 mod array;
 mod function;
 mod hashmap;
+mod union;
 
 pub use array::Array;
 pub use function::Function;
 pub use hashmap::HashMap;
+pub use union::Union;
+
+/// Split a data type value by the separator outside parentheses, brackets, braces and generics.
+pub fn split_value(value: &str, separator: char) -> Vec<String> {
+  let mut parts: Vec<String> = Vec::new();
+  let mut current = String::new();
+  let mut depth: usize = 0;
+  let mut previous: char = ' ';
+
+  for character in value.chars() {
+    match character {
+      '(' | '[' | '{' | '<' => depth += 1,
+      // The `>` of an arrow (`=>`) does not close a generic.
+      '>' if previous == '=' => {},
+      ')' | ']' | '}' | '>' => depth = depth.saturating_sub(1),
+      _ if character == separator && depth == 0 => {
+        parts.push(current.trim().to_string());
+        current = String::new();
+        previous = character;
+        continue;
+      },
+      _ => {},
+    }
+
+    current.push(character);
+    previous = character;
+  }
+
+  parts.push(current.trim().to_string());
+
+  parts
+}
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum Types {
@@ -17,6 +50,9 @@ pub enum Types {
   ARRAY(Array),
   FUNCTION(Function),
   HASHMAP(HashMap),
+  UNION(Union),
+
+  GENERIC(String),
 }
 
 impl Types {
@@ -41,12 +77,31 @@ impl Types {
     }
   }
 
+  pub fn get_union(&self) -> Option<Union> {
+    match self {
+      Types::UNION(union) => Some(union.clone()),
+      _ => None,
+    }
+  }
+
+  pub fn get_generic(&self) -> Option<String> {
+    match self {
+      Types::GENERIC(name) => Some(name.clone()),
+      _ => None,
+    }
+  }
+
   pub fn from_value(value: &str) -> Result<Types, ()> {
     // Parse functions.
     if let Ok(function) = Function::from_value(value) {
       return Ok(Types::FUNCTION(function));
     }
 
+    // Parse unions.
+    if let Some(union) = Union::from_value(value) {
+      return Ok(Types::UNION(union));
+    }
+
     // Parse array.
     if let Ok(array) = Array::from_value(value) {
       return Ok(Types::ARRAY(array));
@@ -3,17 +3,16 @@ use crate::{
   Parser,
   tokens::{
     Signs,
+    split_value,
     Token,
     Tokens,
     Types,
   },
 };
 
-use std::collections::HashMap;
-
 #[derive(Debug, Clone, PartialEq)]
 pub struct Function {
-  arguments: HashMap<String, Token>,
+  arguments: Vec<(String, Token)>,
   data_type: Token,
   value: String,
 }
@@ -21,54 +20,70 @@ pub struct Function {
 impl Function {
   pub fn new(data_type: Token, value: String) -> Function {
     Function {
-      arguments: HashMap::new(),
+      arguments: Vec::new(),
       data_type,
       value,
     }
   }
 
   pub fn from_value(value: &str) -> Result<Function, ()> {
-    let parts: Vec<String> = value.split("=>").map(|x| x.to_string()).collect();
+    if !value.starts_with("(") {
+      return Err(());
+    }
 
-    if !value.starts_with("(") || parts.len() < 2 {
+    let mut depth: usize = 0;
+    let mut end: Option<usize> = None;
+
+    // Get the right parentheses of the arguments.
+    for (index, character) in value.char_indices() {
+      match character {
+        '(' => depth += 1,
+        ')' => {
+          depth -= 1;
+
+          if depth == 0 {
+            end = Some(index);
+            break;
+          }
+        },
+        _ => {},
+      }
+    }
+
+    let end: usize = end.ok_or(())?;
+    let data_type = value[end + 1..].trim();
+
+    if !data_type.starts_with("=>") {
       return Err(());
     }
 
     let mut function = Function::new(
-      Token::from_value(parts.last().unwrap().trim(), 0, 0),
+      Token::from_value(data_type[2..].trim(), 0, 0),
       value.to_string(),
     );
 
-    let arguments = parts[0..parts.len() - 1].join("=>");
-    let arguments = arguments.trim();
+    let arguments = value[1..end].trim();
 
-    if arguments != "()" {
+    if !arguments.is_empty() {
       // Parse function arguments.
-      for argument in arguments[1..].split(",") {
-        let argument: Vec<&str> = argument.split(":").collect();
+      for argument in split_value(arguments, ',') {
+        let argument: Vec<&str> = argument.splitn(2, ":").collect();
 
         if argument.len() < 2 {
           return Err(());
         }
 
-        let data_type = argument[1..].join(":");
-        let mut data_type = data_type.trim();
-
-        if !data_type.starts_with("(") && data_type.ends_with(")") {
-          data_type = &data_type[..data_type.len() - 1];
-        }
-
-        function.arguments.insert(
+        function.arguments.push((
           argument[0].trim().to_string(),
-          Token::from_value(data_type, 0, 0),
-        );
+          Token::from_value(argument[1].trim(), 0, 0),
+        ));
       }
     }
 
     Ok(function)
   }
 
-  pub fn get_arguments(&self) -> HashMap<String, Token> {
+  pub fn get_arguments(&self) -> Vec<(String, Token)> {
     self.arguments.clone()
   }
 
@@ -122,7 +137,7 @@ impl Function {
       // Parse type.
       match parse_type(parser) {
         Ok(data_type) => {
-          function.arguments.insert(key, data_type.clone());
+          function.arguments.push((key, data_type.clone()));
           function.value.push_str(data_type.value.as_str());
         },
         Err(_) => {
@@ -183,10 +198,26 @@ fn function_from_value() {
     String::from("(name: string) => string"),
   );
 
-  function.arguments.insert(String::from("name"), Token::from_value("string", 0, 0));
+  function.arguments.push((String::from("name"), Token::from_value("string", 0, 0)));
 
   let function_2 = Function::from_value("(name: string) => string");
 
   assert_eq!(function_2.is_ok(), true);
   assert_eq!(function_2.unwrap(), function);
 }
+
+#[test]
+fn function_from_value_callback() {
+  let mut function = Function::new(
+    Token::from_value("void", 0, 0),
+    String::from("(callback: (one: number, two: number) => string, times: number) => void"),
+  );
+
+  function.arguments.push((String::from("callback"), Token::from_value("(one: number, two: number) => string", 0, 0)));
+  function.arguments.push((String::from("times"), Token::from_value("number", 0, 0)));
+
+  let function_2 = Function::from_value("(callback: (one: number, two: number) => string, times: number) => void");
+
+  assert!(function_2.is_ok());
+  assert_eq!(function_2.unwrap(), function);
+}
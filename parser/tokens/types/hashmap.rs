@@ -3,6 +3,7 @@ use crate::{
   Parser,
   tokens::{
     Signs,
+    split_value,
     Token,
     Tokens,
     Types,
@@ -33,8 +34,8 @@ impl HashMap {
     let mut hashmap = HashMap::new(value.to_string());
     let new_value: &str = &value[1..value.len() - 1];
 
-    for item in new_value.split(",") {
-      let item: Vec<&str> = item.split(":").collect();
+    for item in split_value(new_value, ',') {
+      let item: Vec<&str> = item.splitn(2, ":").collect();
 
       if item.len() < 2 {
         return Err(());
@@ -42,7 +43,7 @@ impl HashMap {
 
       hashmap.items.insert(
         item[0].trim().to_string(),
-        Token::from_value(item[1..].join(":").trim(), 0, 0),
+        Token::from_value(item[1].trim(), 0, 0),
       );
     }
 
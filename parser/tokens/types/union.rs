@@ -0,0 +1,135 @@
+use crate::{
+  parse_type,
+  Parser,
+  tokens::{
+    Signs,
+    split_value,
+    Token,
+    Tokens,
+    Types,
+  },
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Union {
+  types: Vec<Token>,
+  value: String,
+}
+
+impl Union {
+  pub fn new(types: Vec<Token>) -> Union {
+    let mut union = Union {
+      types: Vec::new(),
+      value: String::new(),
+    };
+
+    for data_type in types.iter() {
+      union.add_type(data_type.clone());
+    }
+
+    union
+  }
+
+  /// Add a type to the union, flattening the nested unions and skipping the repeated types.
+  fn add_type(&mut self, data_type: Token) {
+    if let Some(Types::UNION(union)) = data_type.token.get_type() {
+      for union_type in union.get_types().iter() {
+        self.add_type(union_type.clone());
+      }
+
+      return;
+    }
+
+    for union_type in self.types.iter() {
+      if union_type.value == data_type.value {
+        return;
+      }
+    }
+
+    if !self.value.is_empty() {
+      self.value.push_str(" | ");
+    }
+
+    self.value.push_str(data_type.value.as_str());
+    self.types.push(data_type);
+  }
+
+  pub fn from_value(value: &str) -> Option<Union> {
+    let parts: Vec<String> = split_value(value, '|');
+
+    if parts.len() < 2 {
+      return None;
+    }
+
+    let mut types: Vec<Token> = Vec::new();
+
+    for part in parts.iter() {
+      if part.is_empty() {
+        return None;
+      }
+
+      let mut data_type = Token::from_value(part, 0, 0);
+
+      if data_type.token.is_illegal() {
+        data_type.token = Box::new(Tokens::IDENTIFIER);
+      }
+
+      types.push(data_type);
+    }
+
+    Some(Union::new(types))
+  }
+
+  pub fn get_types(&self) -> Vec<Token> {
+    self.types.clone()
+  }
+
+  pub fn get_value(&self) -> String {
+    self.value.clone()
+  }
+
+  pub fn parse(parser: &mut Parser, token: Token) -> Result<Token, i32> {
+    if !parser.next_token_is(Signs::new(Signs::BITOR)) {
+      return Err(0);
+    }
+
+    let mut types: Vec<Token> = vec![token.clone()];
+
+    while parser.expect_token(Signs::new(Signs::BITOR)) {
+      // Get the next token.
+      parser.next_token();
+
+      // Parse type.
+      match parse_type(parser) {
+        Ok(data_type) => {
+          types.push(data_type);
+        },
+        Err(_) => {
+          return Err(1);
+        },
+      }
+    }
+
+    let union = Union::new(types);
+    let value = union.get_value();
+
+    Ok(Token::new(
+      Box::new(Tokens::TYPE(Types::UNION(union))),
+      value,
+      token.line,
+      token.position,
+    ))
+  }
+}
+
+#[test]
+fn union_from_value() {
+  let union = Union::new(vec![
+    Token::from_value("string", 0, 0),
+    Token::from_value("null", 0, 0),
+  ]);
+  let union_2 = Union::from_value("string | null");
+
+  assert!(union_2.is_some());
+  assert_eq!(union_2.unwrap(), union);
+}